@@ -22,7 +22,15 @@ extern crate pkg_config;
 
 use std::env;
 
+/// A selectable backend: the name of its cargo feature and the build step.
+type Backend = (&'static str, Box<dyn Fn()>);
+
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(hidapi)");
+    println!("cargo:rustc-check-cfg=cfg(libusb)");
+    println!("cargo:rustc-check-cfg=cfg(linux_native)");
+    println!("cargo:rustc-check-cfg=cfg(docsrs)");
+
     let target = env::var("TARGET").unwrap();
 
     if target.contains("linux") {
@@ -46,7 +54,7 @@ fn compile_linux() {
     // First check the features enabled for the crate.
     // Only one linux backend should be enabled at a time.
 
-    let avail_backends: [(&'static str, Box<dyn Fn()>); 4] = [
+    let avail_backends: [Backend; 5] = [
         (
             "LINUX_STATIC_HIDRAW",
             Box::new(|| {
@@ -56,6 +64,7 @@ fn compile_linux() {
                     .include("etc/hidapi/hidapi");
                 pkg_config::probe_library("libudev").expect("Unable to find libudev");
                 config.compile("libhidapi.a");
+                println!("cargo:rustc-cfg=hidapi");
             }),
         ),
         (
@@ -74,6 +83,7 @@ fn compile_linux() {
                     );
                 }
                 config.compile("libhidapi.a");
+                println!("cargo:rustc-cfg=hidapi");
                 println!("cargo:rustc-cfg=libusb");
             }),
         ),
@@ -81,15 +91,26 @@ fn compile_linux() {
             "LINUX_SHARED_HIDRAW",
             Box::new(|| {
                 pkg_config::probe_library("hidapi-hidraw").expect("Unable to find hidapi-hidraw");
+                println!("cargo:rustc-cfg=hidapi");
             }),
         ),
         (
             "LINUX_SHARED_LIBUSB",
             Box::new(|| {
                 pkg_config::probe_library("hidapi-libusb").expect("Unable to find hidapi-libusb");
+                probe_libusb();
+                println!("cargo:rustc-cfg=hidapi");
                 println!("cargo:rustc-cfg=libusb");
             }),
         ),
+        (
+            "LINUX_NATIVE_HIDRAW",
+            Box::new(|| {
+                // Pure rust implementation on top of hidraw and sysfs,
+                // nothing to compile or link.
+                println!("cargo:rustc-cfg=linux_native");
+            }),
+        ),
     ];
 
     let mut backends = avail_backends
@@ -114,13 +135,23 @@ fn compile_linux() {
 //
 //}
 
+/// Link libusb itself, which the hotplug monitor of the libusb backends calls
+/// directly. A shared hidapi library does not pull it in for us.
+fn probe_libusb() {
+    pkg_config::probe_library("libusb-1.0").expect("Unable to find libusb-1.0");
+}
+
 fn compile_freebsd() {
     pkg_config::probe_library("hidapi").expect("Unable to find hidapi");
+    probe_libusb();
+    println!("cargo:rustc-cfg=hidapi");
     println!("cargo:rustc-cfg=libusb");
 }
 
 fn compile_openbsd() {
     pkg_config::probe_library("hidapi-libusb").expect("Unable to find hidapi");
+    probe_libusb();
+    println!("cargo:rustc-cfg=hidapi");
     println!("cargo:rustc-cfg=libusb");
 }
 
@@ -128,7 +159,7 @@ fn compile_illumos() {
     // First check the features enabled for the crate.
     // Only one illumos backend should be enabled at a time.
 
-    let avail_backends: [(&'static str, Box<dyn Fn()>); 2] = [
+    let avail_backends: [Backend; 2] = [
         (
             "ILLUMOS_STATIC_LIBUSB",
             Box::new(|| {
@@ -151,6 +182,7 @@ fn compile_illumos() {
             "ILLUMOS_SHARED_LIBUSB",
             Box::new(|| {
                 pkg_config::probe_library("hidapi-libusb").expect("Unable to find hidapi-libusb");
+                probe_libusb();
             }),
         ),
     ];
@@ -166,6 +198,7 @@ fn compile_illumos() {
     // Build it!
     (backends.next().unwrap().1)();
 
+    println!("cargo:rustc-cfg=hidapi");
     println!("cargo:rustc-cfg=libusb");
 }
 
@@ -182,6 +215,7 @@ fn compile_windows() {
     }
     cc.compile("libhidapi.a");
     println!("cargo:rustc-link-lib=setupapi");
+    println!("cargo:rustc-cfg=hidapi");
 }
 
 fn compile_macos() {
@@ -191,5 +225,6 @@ fn compile_macos() {
         .compile("libhidapi.a");
     println!("cargo:rustc-link-lib=framework=IOKit");
     println!("cargo:rustc-link-lib=framework=CoreFoundation");
-    println!("cargo:rustc-link-lib=framework=AppKit")
+    println!("cargo:rustc-link-lib=framework=AppKit");
+    println!("cargo:rustc-cfg=hidapi");
 }
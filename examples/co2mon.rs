@@ -25,6 +25,7 @@ const PACKET_SIZE: usize = 8;
 enum CO2Result {
     Temperature(f32),
     Concentration(u16),
+    #[allow(dead_code)]
     Unknown(u8, u16),
     Error(&'static str),
 }
@@ -47,7 +48,7 @@ fn decode_buf(buf: [u8; PACKET_SIZE]) -> CO2Result {
 
     let magic_word = b"Htemp99e";
     for i in 0..PACKET_SIZE {
-        let sub_val: u8 = (magic_word[i] << 4) | (magic_word[i] >> 4);
+        let sub_val: u8 = magic_word[i].rotate_right(4);
         res[i] = u8::overflowing_sub(res[i], sub_val).0;
     }
 
@@ -8,7 +8,20 @@
 
 extern crate hidapi;
 
-use hidapi::HidApi;
+use hidapi::{DeviceInfo, HidApi};
+
+#[cfg(not(all(libusb, target_os = "linux")))]
+fn usage(device: &DeviceInfo) -> String {
+    format!(
+        ", Usage: {}",
+        hidapi::usage_tables::describe(device.usage_page(), device.usage())
+    )
+}
+
+#[cfg(all(libusb, target_os = "linux"))]
+fn usage(_device: &DeviceInfo) -> String {
+    String::new()
+}
 
 fn main() {
     println!("Printing all available hid devices:");
@@ -17,17 +30,12 @@ fn main() {
         Ok(api) => {
             for device in api.device_list() {
                 println!(
-                    "VID: {:04x}, PID: {:04x}, Serial: {}, Product name: {}",
+                    "VID: {:04x}, PID: {:04x}, Serial: {}, Product name: {}{}",
                     device.vendor_id(),
                     device.product_id(),
-                    match device.serial_number() {
-                        Some(s) => s,
-                        _ => "<COULD NOT FETCH>",
-                    },
-                    match device.product_string() {
-                        Some(s) => s,
-                        _ => "<COULD NOT FETCH>",
-                    }
+                    device.serial_number().unwrap_or("<COULD NOT FETCH>"),
+                    device.product_string().unwrap_or("<COULD NOT FETCH>"),
+                    usage(device)
                 );
             }
         }
@@ -16,6 +16,7 @@ fn main() {
     let api = HidApi::new().expect("Failed to create API instance");
 
     let joystick = api.open(1103, 45320).expect("Failed to open device");
+    let layout = joystick.get_report_layout().ok();
 
     loop {
         let mut buf = [0u8; 256];
@@ -23,8 +24,17 @@ fn main() {
 
         let mut data_string = String::new();
 
-        for u in &buf[..res] {
-            data_string.push_str(&(u.to_string() + "\t"));
+        match layout.as_ref().map(|l| l.decode_input(&buf[..res])) {
+            Some(Ok(values)) => {
+                for v in values {
+                    data_string.push_str(&format!("{}={}\t", v.usage, v.value));
+                }
+            }
+            _ => {
+                for u in &buf[..res] {
+                    data_string.push_str(&(u.to_string() + "\t"));
+                }
+            }
         }
 
         println!("{}", data_string);
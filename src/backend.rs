@@ -0,0 +1,189 @@
+//! Traits implemented by the different `hidapi` backends.
+//!
+//! [`HidApi`](crate::HidApi) and [`HidDevice`](crate::HidDevice) forward all
+//! calls to an implementation of these traits. By default the backend selected
+//! with the crate feature flags is used, but any other implementation (like the
+//! one in the [`mock`](crate::mock) module) can be passed to
+//! [`HidApi::with_backend()`](crate::HidApi::with_backend).
+
+use std::ffi::CStr;
+#[cfg(unix)]
+use std::os::unix::io::RawFd;
+
+use crate::hotplug::PollingMonitor;
+use crate::{DeviceInfo, HidError, HidResult};
+
+/// Enumerates and opens devices.
+///
+/// Implementations must be thread safe, because a [`HidApi`](crate::HidApi)
+/// is `Send` and `Sync`.
+pub trait HidApiBackend: Send + Sync {
+    /// Returns information about all devices currently attached.
+    fn get_hid_device_info_vector(&self) -> HidResult<Vec<DeviceInfo>>;
+
+    /// Returns information about the devices with matching vendor and product
+    /// id, where 0 matches any id.
+    ///
+    /// The default implementation filters the result of
+    /// `get_hid_device_info_vector()`.
+    fn enumerate(&self, vendor_id: u16, product_id: u16) -> HidResult<Vec<DeviceInfo>> {
+        let mut devices = self.get_hid_device_info_vector()?;
+        devices.retain(|info| {
+            (vendor_id == 0 || info.vendor_id() == vendor_id)
+                && (product_id == 0 || info.product_id() == product_id)
+        });
+        Ok(devices)
+    }
+
+    /// Open the first device with matching vendor and product id.
+    fn open(&self, vid: u16, pid: u16) -> HidResult<Box<dyn HidDeviceBackend>>;
+
+    /// Open the first device with matching vendor id, product id and serial number.
+    fn open_serial(&self, vid: u16, pid: u16, sn: &str) -> HidResult<Box<dyn HidDeviceBackend>>;
+
+    /// Open the device with the given backend specific path.
+    fn open_path(&self, device_path: &CStr) -> HidResult<Box<dyn HidDeviceBackend>>;
+
+    /// Open a device using libusb_wrap_sys_device.
+    #[cfg(libusb)]
+    fn wrap_sys_device(
+        &self,
+        _sys_dev: isize,
+        _interface_num: i32,
+    ) -> HidResult<Box<dyn HidDeviceBackend>> {
+        Err(unsupported("wrap_sys_device"))
+    }
+
+    /// Get the last error, which is not related to a specific device.
+    fn check_error(&self) -> HidResult<HidError>;
+
+    /// Called by [`HidApi::shutdown()`](crate::HidApi::shutdown), before the
+    /// backend is dropped. Fails if resources of this backend, which would be
+    /// released, are still in use, like devices opened through it. If it
+    /// fails, the backend is kept.
+    fn shutdown(&self) -> HidResult<()> {
+        Ok(())
+    }
+
+    /// Start watching for devices being attached or detached, see
+    /// [`HidApi::hotplug()`](crate::HidApi::hotplug).
+    ///
+    /// The default implementation re-enumerates the devices once per second.
+    fn hotplug_monitor(&self) -> HidResult<Box<dyn HotplugMonitor>> {
+        Ok(Box::<PollingMonitor>::default())
+    }
+}
+
+/// Notifies about devices being attached or detached.
+pub trait HotplugMonitor: Send {
+    /// Wait up to `timeout` milliseconds for a change, or forever if `timeout`
+    /// is negative.
+    ///
+    /// Returns `true` if devices may have been attached or detached. Spurious
+    /// notifications are fine, because every notification is confirmed by
+    /// enumerating the devices again.
+    fn wait(&mut self, timeout: i32) -> HidResult<bool>;
+}
+
+/// An opened device.
+///
+/// All methods follow the semantics of the corresponding methods of
+/// [`HidDevice`](crate::HidDevice), except for `send_feature_report()`,
+/// which returns the number of bytes sent.
+pub trait HidDeviceBackend: Send + private::IntoDyn {
+    fn check_error(&self) -> HidResult<HidError>;
+
+    fn write(&self, data: &[u8]) -> HidResult<usize>;
+
+    fn read(&self, buf: &mut [u8]) -> HidResult<usize>;
+
+    fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize>;
+
+    fn send_feature_report(&self, data: &[u8]) -> HidResult<usize>;
+
+    fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize>;
+
+    /// See [`HidDevice::get_input_report`](crate::HidDevice::get_input_report).
+    fn get_input_report(&self, _buf: &mut [u8]) -> HidResult<usize> {
+        Err(unsupported("get_input_report"))
+    }
+
+    fn set_blocking_mode(&self, blocking: bool) -> HidResult<()>;
+
+    fn get_manufacturer_string(&self) -> HidResult<Option<String>>;
+
+    fn get_product_string(&self) -> HidResult<Option<String>>;
+
+    fn get_serial_number_string(&self) -> HidResult<Option<String>>;
+
+    fn get_indexed_string(&self, index: i32) -> HidResult<Option<String>>;
+
+    fn get_device_info(&self) -> HidResult<DeviceInfo>;
+
+    /// Returns the report descriptor, truncated to its actual size.
+    fn get_report_descriptor(&self) -> HidResult<Vec<u8>>;
+
+    /// Convert the backend into one, which can be used from multiple threads
+    /// at once.
+    ///
+    /// Backends, which are `Sync`, should return `Ok(self)`. This lets the
+    /// halves returned by [`HidDevice::split()`](crate::HidDevice::split) read
+    /// and write at the same time, instead of taking turns. Others return
+    /// themselves unchanged, which the default implementation does.
+    fn into_sync(self: Box<Self>) -> Result<SyncDeviceBackend, Box<dyn HidDeviceBackend>> {
+        Err(self.into_dyn())
+    }
+
+    /// A file descriptor, which becomes readable when an input report is
+    /// available. Async wrappers use it to wait for reports without a helper
+    /// thread.
+    #[cfg(unix)]
+    fn pollable_fd(&self) -> Option<RawFd> {
+        None
+    }
+
+    /// See [`HidDevice::get_location_id`](crate::HidDevice::get_location_id).
+    #[cfg(target_os = "macos")]
+    fn get_location_id(&self) -> HidResult<u32> {
+        Err(unsupported("get_location_id"))
+    }
+
+    /// See [`HidDevice::is_open_exclusive`](crate::HidDevice::is_open_exclusive).
+    #[cfg(target_os = "macos")]
+    fn is_open_exclusive(&self) -> HidResult<bool> {
+        Err(unsupported("is_open_exclusive"))
+    }
+
+    /// See [`HidDevice::get_container_id`](crate::HidDevice::get_container_id).
+    #[cfg(target_os = "windows")]
+    fn get_container_id(&self) -> HidResult<winapi::shared::guiddef::GUID> {
+        Err(unsupported("get_container_id"))
+    }
+}
+
+/// A backend, which can be used from multiple threads at once, see
+/// [`HidDeviceBackend::into_sync()`].
+pub type SyncDeviceBackend = Box<dyn HidDeviceBackend + Sync>;
+
+mod private {
+    use super::HidDeviceBackend;
+
+    /// Lets the default implementation of `into_sync()` return the backend as
+    /// a trait object. Implemented for all backends.
+    pub trait IntoDyn {
+        fn into_dyn(self: Box<Self>) -> Box<dyn HidDeviceBackend>;
+    }
+
+    impl<T: HidDeviceBackend + 'static> IntoDyn for T {
+        fn into_dyn(self: Box<Self>) -> Box<dyn HidDeviceBackend> {
+            self
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn unsupported(function: &str) -> HidError {
+    HidError::HidApiError {
+        message: format!("{}: not supported by this backend", function),
+    }
+}
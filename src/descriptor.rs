@@ -0,0 +1,1140 @@
+//! Parser for HID report descriptors.
+//!
+//! [`ReportDescriptor::parse()`] decodes the raw bytes returned by
+//! [`HidDevice::get_report_descriptor()`](crate::HidDevice::get_report_descriptor)
+//! into the flat list of items and into a tree of collections, in which every
+//! Input, Output and Feature item carries the global and local state that was
+//! in effect for it. [`ReportLayout`] builds on top of that and computes the
+//! position of every field in the input, output and feature reports, which
+//! is used to decode received reports into [`UsageValue`]s and to encode
+//! outgoing ones.
+//!
+//! ```
+//! use hidapi::descriptor::{CollectionKind, ReportDescriptor, Usage};
+//!
+//! // A mouse with three buttons and relative X/Y axes
+//! let bytes = [
+//!     0x05, 0x01, // Usage Page (Generic Desktop)
+//!     0x09, 0x02, // Usage (Mouse)
+//!     0xA1, 0x01, // Collection (Application)
+//!     0x05, 0x09, //   Usage Page (Button)
+//!     0x19, 0x01, //   Usage Minimum (1)
+//!     0x29, 0x03, //   Usage Maximum (3)
+//!     0x15, 0x00, //   Logical Minimum (0)
+//!     0x25, 0x01, //   Logical Maximum (1)
+//!     0x75, 0x01, //   Report Size (1)
+//!     0x95, 0x03, //   Report Count (3)
+//!     0x81, 0x02, //   Input (Data, Variable, Absolute)
+//!     0x95, 0x05, //   Report Count (5)
+//!     0x81, 0x01, //   Input (Constant)
+//!     0x05, 0x01, //   Usage Page (Generic Desktop)
+//!     0x09, 0x30, //   Usage (X)
+//!     0x09, 0x31, //   Usage (Y)
+//!     0x15, 0x81, //   Logical Minimum (-127)
+//!     0x25, 0x7F, //   Logical Maximum (127)
+//!     0x75, 0x08, //   Report Size (8)
+//!     0x95, 0x02, //   Report Count (2)
+//!     0x81, 0x06, //   Input (Data, Variable, Relative)
+//!     0xC0,       // End Collection
+//! ];
+//!
+//! let descriptor = ReportDescriptor::parse(&bytes).unwrap();
+//! assert_eq!(descriptor.items().len(), 22);
+//!
+//! let mouse = &descriptor.collections()[0];
+//! assert_eq!(mouse.kind(), CollectionKind::Application);
+//! assert_eq!(mouse.usage(), Some(Usage::new(0x01, 0x02)));
+//!
+//! let axes = mouse.data_items().nth(2).unwrap();
+//! assert!(axes.flags().is_relative());
+//! assert_eq!(axes.globals().logical_minimum, -127);
+//! assert_eq!(axes.usage(1), Some(Usage::new(0x01, 0x31)));
+//! ```
+
+use std::fmt;
+
+use crate::{HidError, HidResult};
+
+mod layout;
+mod value;
+
+pub use self::layout::{Field, Report, ReportLayout};
+pub use self::value::UsageValue;
+
+/// A usage, consisting of a usage page and a usage id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Usage {
+    pub page: u16,
+    pub id: u16,
+}
+
+impl Usage {
+    pub fn new(page: u16, id: u16) -> Self {
+        Usage { page, id }
+    }
+
+    /// Create a usage from its 32 bit extended form, which contains the
+    /// usage page in the upper 16 bits.
+    pub fn from_extended(value: u32) -> Self {
+        Usage {
+            page: (value >> 16) as u16,
+            id: value as u16,
+        }
+    }
+
+    /// The 32 bit extended form of the usage.
+    pub fn extended(&self) -> u32 {
+        (self.page as u32) << 16 | self.id as u32
+    }
+
+    /// Describe the usage with the names from the
+    /// [`usage_tables`](crate::usage_tables), e.g. `"Generic Desktop / Mouse"`.
+    pub fn describe(&self) -> crate::usage_tables::Description {
+        crate::usage_tables::describe(self.page, self.id)
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.page, self.id)
+    }
+}
+
+/// The flags of an Input, Output or Feature item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DataFlags(u32);
+
+impl DataFlags {
+    pub fn from_bits(bits: u32) -> Self {
+        DataFlags(bits)
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Constant fields are usually padding.
+    pub fn is_constant(&self) -> bool {
+        self.0 & 0x001 != 0
+    }
+
+    /// Variable fields contain one value per usage.
+    pub fn is_variable(&self) -> bool {
+        self.0 & 0x002 != 0
+    }
+
+    /// Array fields contain indices into the list of usages.
+    pub fn is_array(&self) -> bool {
+        !self.is_variable()
+    }
+
+    pub fn is_relative(&self) -> bool {
+        self.0 & 0x004 != 0
+    }
+
+    pub fn is_wrap(&self) -> bool {
+        self.0 & 0x008 != 0
+    }
+
+    pub fn is_non_linear(&self) -> bool {
+        self.0 & 0x010 != 0
+    }
+
+    pub fn has_preferred_state(&self) -> bool {
+        self.0 & 0x020 == 0
+    }
+
+    pub fn has_null_state(&self) -> bool {
+        self.0 & 0x040 != 0
+    }
+
+    /// Only meaningful for Output and Feature items.
+    pub fn is_volatile(&self) -> bool {
+        self.0 & 0x080 != 0
+    }
+
+    pub fn is_buffered_bytes(&self) -> bool {
+        self.0 & 0x100 != 0
+    }
+}
+
+/// The type of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollectionKind {
+    Physical,
+    Application,
+    Logical,
+    Report,
+    NamedArray,
+    UsageSwitch,
+    UsageModifier,
+    /// Reserved (0x07 - 0x7F) or vendor defined (0x80 - 0xFF) types.
+    Other(u8),
+}
+
+impl CollectionKind {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0x00 => CollectionKind::Physical,
+            0x01 => CollectionKind::Application,
+            0x02 => CollectionKind::Logical,
+            0x03 => CollectionKind::Report,
+            0x04 => CollectionKind::NamedArray,
+            0x05 => CollectionKind::UsageSwitch,
+            0x06 => CollectionKind::UsageModifier,
+            other => CollectionKind::Other(other),
+        }
+    }
+}
+
+/// The kind of report a data item belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReportKind {
+    Input,
+    Output,
+    Feature,
+}
+
+/// Main items define data fields and collections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MainItem {
+    Input(DataFlags),
+    Output(DataFlags),
+    Feature(DataFlags),
+    Collection(CollectionKind),
+    EndCollection,
+}
+
+/// Global items change the state for all following main items.
+///
+/// Signed values are sign extended according to the size of the item. Push
+/// saves the global state and Pop restores it:
+///
+/// ```
+/// use hidapi::descriptor::{GlobalItem, ItemKind, ReportDescriptor};
+///
+/// let bytes = [
+///     0x75, 0x08, // Report Size (8)
+///     0xA4,       // Push
+///     0x75, 0x10, // Report Size (16)
+///     0x95, 0x01, // Report Count (1)
+///     0x81, 0x02, // Input (Data, Variable, Absolute)
+///     0xB4,       // Pop
+///     0x81, 0x02, // Input (Data, Variable, Absolute)
+/// ];
+///
+/// let descriptor = ReportDescriptor::parse(&bytes).unwrap();
+/// assert_eq!(descriptor.items()[1].kind(), &ItemKind::Global(GlobalItem::Push));
+///
+/// let items = descriptor.data_items();
+/// assert_eq!(items[0].globals().report_size, 16);
+/// // The report count was set after the push, so it is restored to 0
+/// assert_eq!(items[1].globals().report_size, 8);
+/// assert_eq!(items[1].globals().report_count, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalItem {
+    UsagePage(u16),
+    LogicalMinimum(i32),
+    LogicalMaximum(i32),
+    PhysicalMinimum(i32),
+    PhysicalMaximum(i32),
+    UnitExponent(i8),
+    Unit(u32),
+    ReportSize(u32),
+    ReportId(u8),
+    ReportCount(u32),
+    Push,
+    Pop,
+}
+
+/// Local items only apply to the next main item.
+///
+/// Usages are stored with the size they were encoded with: a 4 byte usage
+/// contains its own usage page, otherwise `page` is `None` and the usage page
+/// in effect at the next main item applies.
+///
+/// ```
+/// use hidapi::descriptor::{ItemKind, LocalItem, ReportDescriptor, Usage};
+///
+/// let bytes = [
+///     0x05, 0x01,                   // Usage Page (Generic Desktop)
+///     0x0B, 0xE9, 0x00, 0x0C, 0x00, // Usage (Consumer / Volume Increment)
+///     0x09, 0x30,                   // Usage (X)
+///     0x75, 0x01,                   // Report Size (1)
+///     0x95, 0x02,                   // Report Count (2)
+///     0x81, 0x02,                   // Input (Data, Variable, Absolute)
+/// ];
+///
+/// let descriptor = ReportDescriptor::parse(&bytes).unwrap();
+/// assert_eq!(
+///     descriptor.items()[1].kind(),
+///     &ItemKind::Local(LocalItem::Usage { page: Some(0x0C), id: 0xE9 })
+/// );
+///
+/// let item = descriptor.data_items()[0];
+/// assert_eq!(item.usage(0), Some(Usage::new(0x0C, 0xE9)));
+/// assert_eq!(item.usage(1), Some(Usage::new(0x01, 0x30)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalItem {
+    Usage {
+        page: Option<u16>,
+        id: u16,
+    },
+    UsageMinimum {
+        page: Option<u16>,
+        id: u16,
+    },
+    UsageMaximum {
+        page: Option<u16>,
+        id: u16,
+    },
+    DesignatorIndex(u32),
+    DesignatorMinimum(u32),
+    DesignatorMaximum(u32),
+    StringIndex(u32),
+    StringMinimum(u32),
+    StringMaximum(u32),
+    /// `true` opens a delimited set of alternative usages, `false` closes it.
+    Delimiter(bool),
+}
+
+/// The decoded content of an item.
+///
+/// ```
+/// use hidapi::descriptor::{ItemKind, ReportDescriptor};
+///
+/// // Long item with tag 0xF0 and two bytes of data
+/// let bytes = [0xFE, 0x02, 0xF0, 0xAA, 0xBB, 0x05, 0x01];
+///
+/// let descriptor = ReportDescriptor::parse(&bytes).unwrap();
+/// let long = &descriptor.items()[0];
+/// assert_eq!(long.kind(), &ItemKind::Long { tag: 0xF0, data: vec![0xAA, 0xBB] });
+/// assert_eq!(descriptor.items()[1].offset(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemKind {
+    Main(MainItem),
+    Global(GlobalItem),
+    Local(LocalItem),
+    /// A long item. No long item tags are defined by the HID specification.
+    Long {
+        tag: u8,
+        data: Vec<u8>,
+    },
+    /// A short item with a reserved tag.
+    Reserved {
+        prefix: u8,
+        data: u32,
+    },
+}
+
+/// A single item of a report descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    offset: usize,
+    kind: ItemKind,
+}
+
+impl Item {
+    /// Byte offset of the item prefix in the descriptor.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn kind(&self) -> &ItemKind {
+        &self.kind
+    }
+}
+
+/// The global state in effect for a data item.
+///
+/// Ranges are resolved the way most host implementations do: when the
+/// minimum is not negative, the maximum is interpreted as unsigned value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Globals {
+    pub usage_page: u16,
+    pub logical_minimum: i32,
+    pub logical_maximum: i32,
+    pub physical_minimum: i32,
+    pub physical_maximum: i32,
+    pub unit_exponent: i8,
+    pub unit: u32,
+    pub report_size: u32,
+    /// `None` if the device does not use numbered reports.
+    pub report_id: Option<u8>,
+    pub report_count: u32,
+}
+
+impl Globals {
+    /// The physical range of the item. The specification defines that the
+    /// logical range is used, when both physical limits are zero.
+    pub fn physical_range(&self) -> (i32, i32) {
+        if self.physical_minimum == 0 && self.physical_maximum == 0 {
+            (self.logical_minimum, self.logical_maximum)
+        } else {
+            (self.physical_minimum, self.physical_maximum)
+        }
+    }
+}
+
+/// Usages assigned to a data item, in descriptor order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageSpec {
+    Single(Usage),
+    Range { minimum: Usage, maximum: Usage },
+}
+
+impl UsageSpec {
+    fn len(&self) -> u32 {
+        match *self {
+            UsageSpec::Single(_) => 1,
+            UsageSpec::Range { minimum, maximum } => {
+                // A range over all extended usages has one usage more than
+                // fits into an u32
+                (maximum.extended() - minimum.extended()).saturating_add(1)
+            }
+        }
+    }
+}
+
+/// The local state in effect for a data item.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Locals {
+    pub usages: Vec<UsageSpec>,
+    pub designator_index: Option<u32>,
+    pub designator_minimum: Option<u32>,
+    pub designator_maximum: Option<u32>,
+    pub string_index: Option<u32>,
+    pub string_minimum: Option<u32>,
+    pub string_maximum: Option<u32>,
+}
+
+/// An Input, Output or Feature item together with the state it was declared with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataItem {
+    offset: usize,
+    kind: ReportKind,
+    flags: DataFlags,
+    globals: Globals,
+    locals: Locals,
+}
+
+impl DataItem {
+    /// Byte offset of the main item in the descriptor.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn kind(&self) -> ReportKind {
+        self.kind
+    }
+
+    pub fn flags(&self) -> DataFlags {
+        self.flags
+    }
+
+    pub fn globals(&self) -> &Globals {
+        &self.globals
+    }
+
+    pub fn locals(&self) -> &Locals {
+        &self.locals
+    }
+
+    /// Total number of usages, with ranges expanded. Saturates at
+    /// `u32::MAX`.
+    ///
+    /// ```
+    /// use hidapi::descriptor::{ReportDescriptor, Usage};
+    ///
+    /// let bytes = [
+    ///     0x05, 0x01,                   // Usage Page (Generic Desktop)
+    ///     0x1B, 0x00, 0x00, 0x00, 0x00, // Usage Minimum (0000:0000)
+    ///     0x2B, 0xFF, 0xFF, 0xFF, 0xFF, // Usage Maximum (ffff:ffff)
+    ///     0x75, 0x08,                   // Report Size (8)
+    ///     0x95, 0x01,                   // Report Count (1)
+    ///     0x81, 0x00,                   // Input (Data, Array)
+    /// ];
+    ///
+    /// let descriptor = ReportDescriptor::parse(&bytes).unwrap();
+    /// let item = descriptor.data_items()[0];
+    /// assert_eq!(item.usage_count(), u32::MAX);
+    /// assert_eq!(item.usage(0x0001_0002), Some(Usage::new(0x0001, 0x0002)));
+    /// ```
+    pub fn usage_count(&self) -> u32 {
+        self.locals
+            .usages
+            .iter()
+            .fold(0u32, |acc, u| acc.saturating_add(u.len()))
+    }
+
+    /// The usage at `index`, with ranges expanded.
+    ///
+    /// For variable items this is the usage of the `index`th field, where the
+    /// last usage applies to all fields beyond the number of usages. For
+    /// array items `index` is the value of the field minus the logical minimum.
+    pub fn usage(&self, index: u32) -> Option<Usage> {
+        let mut remaining = index;
+        for spec in &self.locals.usages {
+            let len = spec.len();
+            if remaining < len {
+                return Some(match *spec {
+                    UsageSpec::Single(usage) => usage,
+                    UsageSpec::Range { minimum, .. } => {
+                        Usage::from_extended(minimum.extended() + remaining)
+                    }
+                });
+            }
+            remaining -= len;
+        }
+
+        if self.flags.is_variable() {
+            match self.locals.usages.last() {
+                Some(&UsageSpec::Single(usage)) => Some(usage),
+                Some(&UsageSpec::Range { maximum, .. }) => Some(maximum),
+                None => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The index of the first occurrence of `usage`, the inverse of
+    /// [`DataItem::usage()`] for the declared usages.
+    pub(crate) fn usage_index(&self, usage: Usage) -> Option<u32> {
+        let mut index = 0u32;
+        for spec in &self.locals.usages {
+            match *spec {
+                UsageSpec::Single(single) if single == usage => return Some(index),
+                UsageSpec::Range { minimum, maximum }
+                    if (minimum.extended()..=maximum.extended()).contains(&usage.extended()) =>
+                {
+                    return index.checked_add(usage.extended() - minimum.extended());
+                }
+                _ => {}
+            }
+            index = index.checked_add(spec.len())?;
+        }
+        None
+    }
+}
+
+/// A node in the collection tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Collection(Collection),
+    Data(DataItem),
+}
+
+/// A collection and the items it contains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collection {
+    offset: usize,
+    kind: CollectionKind,
+    usage: Option<Usage>,
+    children: Vec<Node>,
+}
+
+impl Collection {
+    /// Byte offset of the Collection item in the descriptor.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn kind(&self) -> CollectionKind {
+        self.kind
+    }
+
+    /// The first usage declared for the collection.
+    pub fn usage(&self) -> Option<Usage> {
+        self.usage
+    }
+
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    /// The data items directly contained in this collection.
+    pub fn data_items(&self) -> impl Iterator<Item = &DataItem> {
+        self.children.iter().filter_map(|node| match *node {
+            Node::Data(ref item) => Some(item),
+            Node::Collection(_) => None,
+        })
+    }
+
+    /// The collections directly contained in this collection.
+    pub fn collections(&self) -> impl Iterator<Item = &Collection> {
+        self.children.iter().filter_map(|node| match *node {
+            Node::Collection(ref collection) => Some(collection),
+            Node::Data(_) => None,
+        })
+    }
+}
+
+/// A parsed report descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportDescriptor {
+    items: Vec<Item>,
+    nodes: Vec<Node>,
+}
+
+impl ReportDescriptor {
+    /// Parse a raw report descriptor.
+    ///
+    /// Fails with [`HidError::InvalidReportDescriptor`] if an item is
+    /// truncated, collections are unbalanced, a pop item has no matching push
+    /// item, delimiters are nested or a report ID of 0 is used. Push items
+    /// without a matching pop item are accepted.
+    ///
+    /// The error carries the offset of the offending item, or the length of
+    /// the descriptor if it ended too early:
+    ///
+    /// ```
+    /// use hidapi::descriptor::ReportDescriptor;
+    /// use hidapi::HidError;
+    ///
+    /// fn error_offset(bytes: &[u8]) -> usize {
+    ///     match ReportDescriptor::parse(bytes) {
+    ///         Err(HidError::InvalidReportDescriptor { offset, .. }) => offset,
+    ///         other => panic!("unexpected result: {:?}", other),
+    ///     }
+    /// }
+    ///
+    /// // Usage Page with 2 bytes of data, but only one present
+    /// assert_eq!(error_offset(&[0x05, 0x01, 0x06, 0x00]), 2);
+    /// // Long item with 4 bytes of data, but only one present
+    /// assert_eq!(error_offset(&[0x05, 0x01, 0xFE, 0x04, 0xF0, 0x00]), 2);
+    /// // Pop without Push
+    /// assert_eq!(error_offset(&[0x75, 0x08, 0xB4]), 2);
+    /// // Report ID 0
+    /// assert_eq!(error_offset(&[0x05, 0x01, 0x85, 0x00]), 2);
+    /// // Collection, which is not closed
+    /// assert_eq!(error_offset(&[0xA1, 0x01, 0x75, 0x08]), 4);
+    /// // End Collection without Collection
+    /// assert_eq!(error_offset(&[0x75, 0x08, 0xC0]), 2);
+    /// // Nested delimiters
+    /// assert_eq!(error_offset(&[0xA9, 0x01, 0xA9, 0x01]), 2);
+    /// ```
+    pub fn parse(bytes: &[u8]) -> HidResult<Self> {
+        Parser::default().parse(bytes)
+    }
+
+    /// All items in descriptor order.
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// The top level nodes. Well formed descriptors only contain collections
+    /// on the top level.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// The top level collections.
+    pub fn collections(&self) -> Vec<&Collection> {
+        self.nodes
+            .iter()
+            .filter_map(|node| match *node {
+                Node::Collection(ref collection) => Some(collection),
+                Node::Data(_) => None,
+            })
+            .collect()
+    }
+
+    /// All data items in descriptor order, regardless of their collection.
+    pub fn data_items(&self) -> Vec<&DataItem> {
+        fn walk<'a>(nodes: &'a [Node], out: &mut Vec<&'a DataItem>) {
+            for node in nodes {
+                match *node {
+                    Node::Data(ref item) => out.push(item),
+                    Node::Collection(ref collection) => walk(&collection.children, out),
+                }
+            }
+        }
+
+        let mut items = Vec::new();
+        walk(&self.nodes, &mut items);
+        items
+    }
+}
+
+fn invalid(offset: usize, reason: &'static str) -> HidError {
+    HidError::InvalidReportDescriptor {
+        offset,
+        reason: reason.into(),
+    }
+}
+
+/// Raw value of a global item, which still needs to be interpreted as
+/// signed or unsigned.
+#[derive(Debug, Clone, Copy, Default)]
+struct RawValue {
+    data: u32,
+    size: usize,
+}
+
+impl RawValue {
+    fn signed(&self) -> i32 {
+        match self.size {
+            1 => self.data as u8 as i8 as i32,
+            2 => self.data as u16 as i16 as i32,
+            _ => self.data as i32,
+        }
+    }
+
+    fn unsigned(&self) -> u32 {
+        self.data
+    }
+}
+
+/// The global state, as declared in the descriptor.
+#[derive(Debug, Clone, Copy, Default)]
+struct GlobalState {
+    usage_page: u16,
+    logical_minimum: RawValue,
+    logical_maximum: RawValue,
+    physical_minimum: RawValue,
+    physical_maximum: RawValue,
+    unit_exponent: i8,
+    unit: u32,
+    report_size: u32,
+    report_id: Option<u8>,
+    report_count: u32,
+}
+
+/// Resolve the maximum of a range: if the minimum is not negative, but the
+/// maximum is when sign extended, the descriptor meant an unsigned value.
+fn resolve_range(minimum: RawValue, maximum: RawValue) -> (i32, i32) {
+    let min = minimum.signed();
+    let mut max = maximum.signed();
+    if min >= 0 && max < 0 {
+        max = maximum.unsigned().min(i32::MAX as u32) as i32;
+    }
+    (min, max)
+}
+
+impl GlobalState {
+    fn resolve(&self) -> Globals {
+        let (logical_minimum, logical_maximum) =
+            resolve_range(self.logical_minimum, self.logical_maximum);
+        let (physical_minimum, physical_maximum) =
+            resolve_range(self.physical_minimum, self.physical_maximum);
+
+        Globals {
+            usage_page: self.usage_page,
+            logical_minimum,
+            logical_maximum,
+            physical_minimum,
+            physical_maximum,
+            unit_exponent: self.unit_exponent,
+            unit: self.unit,
+            report_size: self.report_size,
+            report_id: self.report_id,
+            report_count: self.report_count,
+        }
+    }
+}
+
+/// A usage as declared, before the usage page of the main item is applied.
+#[derive(Debug, Clone, Copy)]
+struct RawUsage {
+    page: Option<u16>,
+    id: u16,
+}
+
+impl RawUsage {
+    fn resolve(&self, usage_page: u16) -> Usage {
+        Usage::new(self.page.unwrap_or(usage_page), self.id)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RawUsageSpec {
+    Single(RawUsage),
+    Range(RawUsage, RawUsage),
+}
+
+#[derive(Debug, Default)]
+struct LocalState {
+    usages: Vec<RawUsageSpec>,
+    usage_minimum: Option<RawUsage>,
+    usage_maximum: Option<RawUsage>,
+    designator_index: Option<u32>,
+    designator_minimum: Option<u32>,
+    designator_maximum: Option<u32>,
+    string_index: Option<u32>,
+    string_minimum: Option<u32>,
+    string_maximum: Option<u32>,
+    /// Whether a delimited set is open, and if a usage was taken from it.
+    in_delimiter: bool,
+    delimiter_usage_taken: bool,
+}
+
+impl LocalState {
+    /// Whether a usage item is used, or ignored as alternative of a usage
+    /// in the same delimited set.
+    fn accept_usage(&mut self) -> bool {
+        if !self.in_delimiter {
+            return true;
+        }
+        let accept = !self.delimiter_usage_taken;
+        self.delimiter_usage_taken = true;
+        accept
+    }
+
+    /// Add a complete usage range to the usage list.
+    fn complete_range(&mut self) {
+        if let (Some(minimum), Some(maximum)) = (self.usage_minimum, self.usage_maximum) {
+            self.usages.push(RawUsageSpec::Range(minimum, maximum));
+            self.usage_minimum = None;
+            self.usage_maximum = None;
+        }
+    }
+
+    fn resolve(&self, offset: usize, usage_page: u16) -> HidResult<Locals> {
+        if self.in_delimiter {
+            return Err(invalid(offset, "main item inside of a delimiter set"));
+        }
+        if self.usage_minimum.is_some() || self.usage_maximum.is_some() {
+            return Err(invalid(offset, "incomplete usage range"));
+        }
+
+        let mut usages = Vec::with_capacity(self.usages.len());
+        for spec in &self.usages {
+            usages.push(match *spec {
+                RawUsageSpec::Single(usage) => UsageSpec::Single(usage.resolve(usage_page)),
+                RawUsageSpec::Range(minimum, maximum) => {
+                    let minimum = minimum.resolve(usage_page);
+                    let maximum = maximum.resolve(usage_page);
+                    if maximum.extended() < minimum.extended() {
+                        return Err(invalid(offset, "usage maximum is smaller than minimum"));
+                    }
+                    UsageSpec::Range { minimum, maximum }
+                }
+            });
+        }
+
+        Ok(Locals {
+            usages,
+            designator_index: self.designator_index,
+            designator_minimum: self.designator_minimum,
+            designator_maximum: self.designator_maximum,
+            string_index: self.string_index,
+            string_minimum: self.string_minimum,
+            string_maximum: self.string_maximum,
+        })
+    }
+}
+
+#[derive(Default)]
+struct Parser {
+    items: Vec<Item>,
+    globals: GlobalState,
+    global_stack: Vec<GlobalState>,
+    locals: LocalState,
+    /// Open collections, the last one is the innermost.
+    open: Vec<Collection>,
+    nodes: Vec<Node>,
+}
+
+impl Parser {
+    fn parse(mut self, bytes: &[u8]) -> HidResult<ReportDescriptor> {
+        let mut pos = 0;
+
+        while pos < bytes.len() {
+            let prefix = bytes[pos];
+
+            if prefix == 0xFE {
+                // Long item: prefix, data size, tag, data
+                let header = bytes
+                    .get(pos + 1..pos + 3)
+                    .ok_or_else(|| invalid(pos, "truncated long item"))?;
+                let (size, tag) = (header[0] as usize, header[1]);
+                let data = bytes
+                    .get(pos + 3..pos + 3 + size)
+                    .ok_or_else(|| invalid(pos, "truncated long item"))?;
+                self.items.push(Item {
+                    offset: pos,
+                    kind: ItemKind::Long {
+                        tag,
+                        data: data.to_vec(),
+                    },
+                });
+                pos += 3 + size;
+                continue;
+            }
+
+            let size = match prefix & 0x03 {
+                3 => 4,
+                s => s as usize,
+            };
+            let data = bytes
+                .get(pos + 1..pos + 1 + size)
+                .ok_or_else(|| invalid(pos, "truncated item"))?;
+            let value = RawValue {
+                data: data
+                    .iter()
+                    .rev()
+                    .fold(0u32, |acc, &b| (acc << 8) | b as u32),
+                size,
+            };
+
+            let kind = match (prefix >> 2) & 0x03 {
+                0 => self.main_item(pos, prefix, value)?,
+                1 => self.global_item(pos, prefix, value)?,
+                2 => self.local_item(pos, prefix, value)?,
+                _ => ItemKind::Reserved {
+                    prefix,
+                    data: value.data,
+                },
+            };
+            self.items.push(Item { offset: pos, kind });
+
+            pos += 1 + size;
+        }
+
+        if !self.open.is_empty() {
+            return Err(invalid(bytes.len(), "collection is not closed"));
+        }
+
+        Ok(ReportDescriptor {
+            items: self.items,
+            nodes: self.nodes,
+        })
+    }
+
+    fn push_node(&mut self, node: Node) {
+        match self.open.last_mut() {
+            Some(collection) => collection.children.push(node),
+            None => self.nodes.push(node),
+        }
+    }
+
+    fn main_item(&mut self, offset: usize, prefix: u8, value: RawValue) -> HidResult<ItemKind> {
+        let item = match prefix & 0xFC {
+            0x80 => MainItem::Input(DataFlags(value.data)),
+            0x90 => MainItem::Output(DataFlags(value.data)),
+            0xB0 => MainItem::Feature(DataFlags(value.data)),
+            0xA0 => MainItem::Collection(CollectionKind::from_u8(value.data as u8)),
+            0xC0 => MainItem::EndCollection,
+            _ => {
+                return Ok(ItemKind::Reserved {
+                    prefix,
+                    data: value.data,
+                })
+            }
+        };
+
+        let locals = self.locals.resolve(offset, self.globals.usage_page)?;
+        self.locals = LocalState::default();
+
+        match item {
+            MainItem::Input(flags) | MainItem::Output(flags) | MainItem::Feature(flags) => {
+                let kind = match item {
+                    MainItem::Input(_) => ReportKind::Input,
+                    MainItem::Output(_) => ReportKind::Output,
+                    _ => ReportKind::Feature,
+                };
+                let data = DataItem {
+                    offset,
+                    kind,
+                    flags,
+                    globals: self.globals.resolve(),
+                    locals,
+                };
+                self.push_node(Node::Data(data));
+            }
+            MainItem::Collection(kind) => {
+                let usage = locals.usages.first().map(|spec| match *spec {
+                    UsageSpec::Single(usage) => usage,
+                    UsageSpec::Range { minimum, .. } => minimum,
+                });
+                self.open.push(Collection {
+                    offset,
+                    kind,
+                    usage,
+                    children: Vec::new(),
+                });
+            }
+            MainItem::EndCollection => {
+                let collection = self
+                    .open
+                    .pop()
+                    .ok_or_else(|| invalid(offset, "end collection without collection"))?;
+                self.push_node(Node::Collection(collection));
+            }
+        }
+
+        Ok(ItemKind::Main(item))
+    }
+
+    fn global_item(&mut self, offset: usize, prefix: u8, value: RawValue) -> HidResult<ItemKind> {
+        let state = &mut self.globals;
+        let item = match prefix & 0xFC {
+            0x04 => {
+                state.usage_page = value.data as u16;
+                GlobalItem::UsagePage(state.usage_page)
+            }
+            0x14 => {
+                state.logical_minimum = value;
+                GlobalItem::LogicalMinimum(value.signed())
+            }
+            0x24 => {
+                state.logical_maximum = value;
+                GlobalItem::LogicalMaximum(value.signed())
+            }
+            0x34 => {
+                state.physical_minimum = value;
+                GlobalItem::PhysicalMinimum(value.signed())
+            }
+            0x44 => {
+                state.physical_maximum = value;
+                GlobalItem::PhysicalMaximum(value.signed())
+            }
+            0x54 => {
+                // Defined as 4 bit signed value, but often encoded as full byte
+                state.unit_exponent = if value.data <= 0x0F {
+                    ((value.data as i8) << 4) >> 4
+                } else {
+                    value.signed() as i8
+                };
+                GlobalItem::UnitExponent(state.unit_exponent)
+            }
+            0x64 => {
+                state.unit = value.data;
+                GlobalItem::Unit(value.data)
+            }
+            0x74 => {
+                state.report_size = value.data;
+                GlobalItem::ReportSize(value.data)
+            }
+            0x84 => {
+                if value.data == 0 || value.data > 0xFF {
+                    return Err(invalid(offset, "report ID must be between 1 and 255"));
+                }
+                state.report_id = Some(value.data as u8);
+                GlobalItem::ReportId(value.data as u8)
+            }
+            0x94 => {
+                state.report_count = value.data;
+                GlobalItem::ReportCount(value.data)
+            }
+            0xA4 => {
+                self.global_stack.push(self.globals);
+                GlobalItem::Push
+            }
+            0xB4 => {
+                self.globals = self
+                    .global_stack
+                    .pop()
+                    .ok_or_else(|| invalid(offset, "pop without push"))?;
+                GlobalItem::Pop
+            }
+            _ => {
+                return Ok(ItemKind::Reserved {
+                    prefix,
+                    data: value.data,
+                })
+            }
+        };
+
+        Ok(ItemKind::Global(item))
+    }
+
+    fn local_item(&mut self, offset: usize, prefix: u8, value: RawValue) -> HidResult<ItemKind> {
+        let usage = if value.size == 4 {
+            RawUsage {
+                page: Some((value.data >> 16) as u16),
+                id: value.data as u16,
+            }
+        } else {
+            RawUsage {
+                page: None,
+                id: value.data as u16,
+            }
+        };
+
+        let state = &mut self.locals;
+        let item = match prefix & 0xFC {
+            0x08 => {
+                if state.accept_usage() {
+                    state.usages.push(RawUsageSpec::Single(usage));
+                }
+                LocalItem::Usage {
+                    page: usage.page,
+                    id: usage.id,
+                }
+            }
+            0x18 => {
+                if state.usage_maximum.is_some() || state.accept_usage() {
+                    state.usage_minimum = Some(usage);
+                    state.complete_range();
+                }
+                LocalItem::UsageMinimum {
+                    page: usage.page,
+                    id: usage.id,
+                }
+            }
+            0x28 => {
+                if state.usage_minimum.is_some() || state.accept_usage() {
+                    state.usage_maximum = Some(usage);
+                    state.complete_range();
+                }
+                LocalItem::UsageMaximum {
+                    page: usage.page,
+                    id: usage.id,
+                }
+            }
+            0x38 => {
+                state.designator_index = Some(value.data);
+                LocalItem::DesignatorIndex(value.data)
+            }
+            0x48 => {
+                state.designator_minimum = Some(value.data);
+                LocalItem::DesignatorMinimum(value.data)
+            }
+            0x58 => {
+                state.designator_maximum = Some(value.data);
+                LocalItem::DesignatorMaximum(value.data)
+            }
+            0x78 => {
+                state.string_index = Some(value.data);
+                LocalItem::StringIndex(value.data)
+            }
+            0x88 => {
+                state.string_minimum = Some(value.data);
+                LocalItem::StringMinimum(value.data)
+            }
+            0x98 => {
+                state.string_maximum = Some(value.data);
+                LocalItem::StringMaximum(value.data)
+            }
+            0xA8 => {
+                let open = value.data == 1;
+                if open == state.in_delimiter {
+                    return Err(invalid(
+                        offset,
+                        if open {
+                            "nested delimiter set"
+                        } else {
+                            "close delimiter without open delimiter"
+                        },
+                    ));
+                }
+                state.in_delimiter = open;
+                state.delimiter_usage_taken = false;
+                LocalItem::Delimiter(open)
+            }
+            _ => {
+                return Ok(ItemKind::Reserved {
+                    prefix,
+                    data: value.data,
+                })
+            }
+        };
+
+        Ok(ItemKind::Local(item))
+    }
+}
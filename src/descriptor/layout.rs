@@ -0,0 +1,252 @@
+//! Bit level layout of the reports declared by a report descriptor.
+
+use super::{invalid, DataFlags, DataItem, ReportDescriptor, ReportKind, Usage, UsageSpec};
+use crate::HidResult;
+
+/// A field of a report: `count` values of `bit_size` bits each, starting at
+/// `bit_offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    bit_offset: usize,
+    item: DataItem,
+}
+
+impl Field {
+    /// Offset of the first value in bits, counted from the start of the report
+    /// data, i.e. without the report ID byte.
+    pub fn bit_offset(&self) -> usize {
+        self.bit_offset
+    }
+
+    /// Size of a single value in bits.
+    pub fn bit_size(&self) -> usize {
+        self.item.globals().report_size as usize
+    }
+
+    /// Number of values.
+    pub fn count(&self) -> usize {
+        self.item.globals().report_count as usize
+    }
+
+    /// Size of all values in bits.
+    pub fn bit_len(&self) -> usize {
+        self.bit_size() * self.count()
+    }
+
+    pub fn flags(&self) -> DataFlags {
+        self.item.flags()
+    }
+
+    pub fn logical_range(&self) -> (i32, i32) {
+        let globals = self.item.globals();
+        (globals.logical_minimum, globals.logical_maximum)
+    }
+
+    /// The physical range, which is the logical range if none was declared.
+    pub fn physical_range(&self) -> (i32, i32) {
+        self.item.globals().physical_range()
+    }
+
+    /// The unit, encoded as defined by the HID specification.
+    pub fn unit(&self) -> u32 {
+        self.item.globals().unit
+    }
+
+    pub fn unit_exponent(&self) -> i8 {
+        self.item.globals().unit_exponent
+    }
+
+    /// The usages of the field, ranges are not expanded.
+    pub fn usages(&self) -> &[UsageSpec] {
+        &self.item.locals().usages
+    }
+
+    /// See [`DataItem::usage()`].
+    pub fn usage(&self, index: u32) -> Option<Usage> {
+        self.item.usage(index)
+    }
+
+    /// The data item this field was declared with.
+    pub fn item(&self) -> &DataItem {
+        &self.item
+    }
+}
+
+/// A single report, identified by its kind and report ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    kind: ReportKind,
+    report_id: Option<u8>,
+    fields: Vec<Field>,
+    bit_len: usize,
+}
+
+impl Report {
+    pub fn kind(&self) -> ReportKind {
+        self.kind
+    }
+
+    /// `None` if the device does not use numbered reports.
+    pub fn report_id(&self) -> Option<u8> {
+        self.report_id
+    }
+
+    /// The fields in the order they appear in the report, including padding.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Length of the report data in bits, without the report ID.
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    /// Length of the report data in bytes, without the report ID.
+    pub fn byte_len(&self) -> usize {
+        self.bit_len.div_ceil(8)
+    }
+
+    /// Size of the buffer used to transfer this report with a
+    /// [`HidDevice`](crate::HidDevice).
+    ///
+    /// Output and feature reports always start with the report ID, or 0 for
+    /// devices without numbered reports. Input reports only start with the
+    /// report ID, if the device uses numbered reports.
+    pub fn buffer_len(&self) -> usize {
+        match (self.kind, self.report_id) {
+            (ReportKind::Input, None) => self.byte_len(),
+            _ => self.byte_len() + 1,
+        }
+    }
+}
+
+/// All reports declared by a report descriptor.
+///
+/// ```
+/// use hidapi::descriptor::{ReportKind, ReportLayout};
+///
+/// let bytes = [
+///     0x06, 0x00, 0xFF, // Usage Page (Vendor Defined 0xFF00)
+///     0x09, 0x01,       // Usage (0x01)
+///     0xA1, 0x01,       // Collection (Application)
+///     0x85, 0x01,       //   Report ID (1)
+///     0x15, 0x00,       //   Logical Minimum (0)
+///     0x26, 0xFF, 0x00, //   Logical Maximum (255)
+///     0x75, 0x08,       //   Report Size (8)
+///     0x95, 0x3F,       //   Report Count (63)
+///     0x09, 0x02,       //   Usage (0x02)
+///     0x81, 0x02,       //   Input (Data, Variable, Absolute)
+///     0x09, 0x03,       //   Usage (0x03)
+///     0x91, 0x02,       //   Output (Data, Variable, Absolute)
+///     0xC0,             // End Collection
+/// ];
+///
+/// let layout = ReportLayout::parse(&bytes).unwrap();
+/// assert!(layout.uses_report_ids());
+///
+/// let input = layout.report(ReportKind::Input, Some(1)).unwrap();
+/// assert_eq!(input.byte_len(), 63);
+/// // Numbered input reports are read together with their report ID
+/// assert_eq!(input.buffer_len(), 64);
+/// assert_eq!(layout.max_buffer_len(ReportKind::Output), 64);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportLayout {
+    reports: Vec<Report>,
+    uses_report_ids: bool,
+}
+
+impl ReportLayout {
+    /// Compute the layout of all reports of a parsed descriptor.
+    ///
+    /// Fails with [`HidError::InvalidReportDescriptor`](crate::HidError::InvalidReportDescriptor)
+    /// if numbered and unnumbered reports are mixed.
+    pub fn new(descriptor: &ReportDescriptor) -> HidResult<Self> {
+        let items = descriptor.data_items();
+        let uses_report_ids = items.iter().any(|item| item.globals().report_id.is_some());
+
+        let mut reports: Vec<Report> = Vec::new();
+        for item in items {
+            let report_id = item.globals().report_id;
+            if uses_report_ids && report_id.is_none() {
+                return Err(invalid(
+                    item.offset(),
+                    "data item without report ID in a descriptor using report IDs",
+                ));
+            }
+
+            let bit_len = (item.globals().report_size as usize)
+                .checked_mul(item.globals().report_count as usize)
+                .ok_or_else(|| invalid(item.offset(), "report size overflow"))?;
+
+            let index = match reports
+                .iter()
+                .position(|r| r.kind == item.kind() && r.report_id == report_id)
+            {
+                Some(index) => index,
+                None => {
+                    reports.push(Report {
+                        kind: item.kind(),
+                        report_id,
+                        fields: Vec::new(),
+                        bit_len: 0,
+                    });
+                    reports.len() - 1
+                }
+            };
+
+            let report = &mut reports[index];
+            report.fields.push(Field {
+                bit_offset: report.bit_len,
+                item: item.clone(),
+            });
+            report.bit_len = report
+                .bit_len
+                .checked_add(bit_len)
+                .ok_or_else(|| invalid(item.offset(), "report size overflow"))?;
+        }
+
+        reports.sort_by_key(|r| (r.kind, r.report_id));
+
+        Ok(ReportLayout {
+            reports,
+            uses_report_ids,
+        })
+    }
+
+    /// Parse a raw report descriptor and compute its layout.
+    pub fn parse(bytes: &[u8]) -> HidResult<Self> {
+        ReportLayout::new(&ReportDescriptor::parse(bytes)?)
+    }
+
+    /// Whether the reports of the device are numbered.
+    pub fn uses_report_ids(&self) -> bool {
+        self.uses_report_ids
+    }
+
+    /// All reports, sorted by kind and report ID.
+    pub fn reports(&self) -> &[Report] {
+        &self.reports
+    }
+
+    /// The reports of one kind, sorted by report ID.
+    pub fn reports_of_kind(&self, kind: ReportKind) -> impl Iterator<Item = &Report> {
+        self.reports.iter().filter(move |r| r.kind == kind)
+    }
+
+    /// Look up a report by kind and report ID.
+    pub fn report(&self, kind: ReportKind, report_id: Option<u8>) -> Option<&Report> {
+        self.reports
+            .iter()
+            .find(|r| r.kind == kind && r.report_id == report_id)
+    }
+
+    /// The buffer size needed to transfer any report of the given kind,
+    /// see [`Report::buffer_len()`].
+    pub fn max_buffer_len(&self, kind: ReportKind) -> usize {
+        self.reports_of_kind(kind)
+            .map(Report::buffer_len)
+            .max()
+            .unwrap_or(0)
+    }
+}
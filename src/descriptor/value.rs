@@ -0,0 +1,330 @@
+//! Extraction and injection of field values.
+
+use super::{Field, Report, ReportKind, ReportLayout, Usage};
+use crate::{HidError, HidResult};
+
+/// A value decoded from a report.
+///
+/// ```
+/// use hidapi::descriptor::{ReportKind, ReportLayout, Usage};
+///
+/// let bytes = [
+///     0x05, 0x01,       // Usage Page (Generic Desktop)
+///     0x09, 0x04,       // Usage (Joystick)
+///     0xA1, 0x01,       // Collection (Application)
+///     0x09, 0x30,       //   Usage (X)
+///     0x16, 0x00, 0xFE, //   Logical Minimum (-512)
+///     0x26, 0xFF, 0x01, //   Logical Maximum (511)
+///     0x36, 0x00, 0xFC, //   Physical Minimum (-1024)
+///     0x46, 0xFE, 0x03, //   Physical Maximum (1022)
+///     0x55, 0x0E,       //   Unit Exponent (-2)
+///     0x75, 0x0A,       //   Report Size (10)
+///     0x95, 0x01,       //   Report Count (1)
+///     0x81, 0x02,       //   Input (Data, Variable, Absolute)
+///     0x75, 0x06,       //   Report Size (6)
+///     0x81, 0x01,       //   Input (Constant)
+///     0x05, 0x08,       //   Usage Page (LED)
+///     0x09, 0x4B,       //   Usage (Generic Indicator)
+///     0x15, 0x00,       //   Logical Minimum (0)
+///     0x25, 0x01,       //   Logical Maximum (1)
+///     0x35, 0x00,       //   Physical Minimum (0)
+///     0x45, 0x00,       //   Physical Maximum (0)
+///     0x55, 0x00,       //   Unit Exponent (0)
+///     0x75, 0x08,       //   Report Size (8)
+///     0x91, 0x02,       //   Output (Data, Variable, Absolute)
+///     0xC0,             // End Collection
+/// ];
+/// let layout = ReportLayout::parse(&bytes).unwrap();
+///
+/// // X = -3, sign extended from 10 bits
+/// let values = layout.decode_input(&[0xFD, 0x03]).unwrap();
+/// assert_eq!(values.len(), 1);
+/// assert_eq!(values[0].usage, Usage::new(0x01, 0x30));
+/// assert_eq!(values[0].value, -3);
+/// assert_eq!(values[0].physical, -0.06);
+///
+/// let output = layout.report(ReportKind::Output, None).unwrap();
+/// let buf = output.encode(&[(Usage::new(0x08, 0x4B), 1)]).unwrap();
+/// assert_eq!(buf, [0x00, 0x01]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UsageValue {
+    pub usage: Usage,
+    /// The logical value, sign extended if the logical minimum is negative.
+    ///
+    /// Array fields only report the usages that are currently active, with a
+    /// value of 1. Empty slots and the reserved usage ID 0 are skipped.
+    pub value: i32,
+    /// The value converted to physical units, including the unit exponent.
+    pub physical: f64,
+}
+
+impl Field {
+    /// Read the value at `index` from the report data, without the report ID.
+    ///
+    /// Returns `None` if the data is too short or a value is wider than 32 bits.
+    pub fn extract(&self, data: &[u8], index: usize) -> Option<i32> {
+        if index >= self.count() {
+            return None;
+        }
+
+        let size = self.bit_size();
+        let raw = read_bits(data, self.bit_offset() + index * size, size)?;
+        if self.logical_range().0 < 0 && size < 32 {
+            let shift = 32 - size as u32;
+            Some(((raw << shift) as i32) >> shift)
+        } else {
+            Some(raw as i32)
+        }
+    }
+
+    /// Write the value at `index` into the report data, without the report ID.
+    ///
+    /// The value is truncated to the size of the field.
+    pub fn insert(&self, data: &mut [u8], index: usize, value: i32) -> HidResult<()> {
+        let size = self.bit_size();
+        if index >= self.count() || size > 32 {
+            return Err(invalid_report(format!(
+                "can not write value {} of a field with {} values of {} bits",
+                index,
+                self.count(),
+                size
+            )));
+        }
+
+        write_bits(data, self.bit_offset() + index * size, size, value as u32)
+    }
+
+    /// Convert a logical value into physical units, applying the unit exponent.
+    pub fn to_physical(&self, value: i32) -> f64 {
+        let (logical_min, logical_max) = self.logical_range();
+        let (physical_min, physical_max) = self.physical_range();
+
+        let physical = if logical_max == logical_min {
+            physical_min as f64
+        } else {
+            let scale = (physical_max as f64 - physical_min as f64)
+                / (logical_max as f64 - logical_min as f64);
+            physical_min as f64 + (value as f64 - logical_min as f64) * scale
+        };
+
+        physical * 10f64.powi(self.unit_exponent() as i32)
+    }
+
+    fn in_logical_range(&self, value: i32) -> bool {
+        let (min, max) = self.logical_range();
+        min <= value && value <= max
+    }
+
+    fn decode_into(&self, data: &[u8], values: &mut Vec<UsageValue>) {
+        if self.flags().is_constant() || self.bit_size() > 32 {
+            return;
+        }
+
+        for index in 0..self.count() {
+            let value = match self.extract(data, index) {
+                Some(value) => value,
+                None => return,
+            };
+
+            if self.flags().is_variable() {
+                if let Some(usage) = self.usage(index as u32) {
+                    values.push(UsageValue {
+                        usage,
+                        value,
+                        physical: self.to_physical(value),
+                    });
+                }
+            } else if self.in_logical_range(value) {
+                // Usage ID 0 is reserved on all pages and used as "no event"
+                let usage_index = (value as i64 - self.logical_range().0 as i64) as u32;
+                if let Some(usage) = self.usage(usage_index).filter(|u| u.id != 0) {
+                    values.push(UsageValue {
+                        usage,
+                        value: 1,
+                        physical: 1.0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Report {
+    /// Decode a report as returned by [`HidDevice::read()`](crate::HidDevice::read)
+    /// or [`HidDevice::get_feature_report()`](crate::HidDevice::get_feature_report).
+    ///
+    /// The buffer has to start with the report ID, if the report is numbered or
+    /// not an input report, see [`Report::buffer_len()`]. Padding and constant
+    /// fields are skipped, as are values wider than 32 bits.
+    pub fn decode(&self, buf: &[u8]) -> HidResult<Vec<UsageValue>> {
+        let data = self.strip_report_id(buf)?;
+        if data.len() < self.byte_len() {
+            return Err(invalid_report(format!(
+                "report is {} bytes long, expected {}",
+                data.len(),
+                self.byte_len()
+            )));
+        }
+
+        let mut values = Vec::new();
+        for field in self.fields() {
+            field.decode_into(data, &mut values);
+        }
+        Ok(values)
+    }
+
+    /// Encode usage values into a buffer ready for
+    /// [`HidDevice::write()`](crate::HidDevice::write) or
+    /// [`HidDevice::send_feature_report()`](crate::HidDevice::send_feature_report).
+    ///
+    /// Values for variable fields are written to the first field with a
+    /// matching usage. For array fields a non-zero value marks the usage as
+    /// active. Fields without a value are left zero.
+    pub fn encode(&self, values: &[(Usage, i32)]) -> HidResult<Vec<u8>> {
+        let mut buf = vec![0u8; self.buffer_len()];
+        let offset = buf.len() - self.byte_len();
+        if offset == 1 {
+            buf[0] = self.report_id().unwrap_or(0);
+        }
+
+        let mut array_slots = vec![0usize; self.fields().len()];
+        for &(usage, value) in values {
+            self.encode_value(&mut buf[offset..], &mut array_slots, usage, value)?;
+        }
+        Ok(buf)
+    }
+
+    fn encode_value(
+        &self,
+        data: &mut [u8],
+        array_slots: &mut [usize],
+        usage: Usage,
+        value: i32,
+    ) -> HidResult<()> {
+        for (field, next_slot) in self.fields().iter().zip(array_slots.iter_mut()) {
+            if field.flags().is_constant() {
+                continue;
+            }
+
+            if field.flags().is_variable() {
+                let index = match (0..field.count()).find(|&i| field.usage(i as u32) == Some(usage))
+                {
+                    Some(index) => index,
+                    None => continue,
+                };
+                if !field.in_logical_range(value) {
+                    let (min, max) = field.logical_range();
+                    return Err(invalid_report(format!(
+                        "value {} for usage {} is outside of the logical range {}..={}",
+                        value, usage, min, max
+                    )));
+                }
+                return field.insert(data, index, value);
+            }
+
+            let usage_index = match field.item().usage_index(usage) {
+                Some(index) => index,
+                None => continue,
+            };
+            if value == 0 {
+                return Ok(());
+            }
+            if *next_slot >= field.count() {
+                return Err(invalid_report(format!(
+                    "too many active usages for the array containing {}",
+                    usage
+                )));
+            }
+            let logical = field.logical_range().0 as i64 + usage_index as i64;
+            field.insert(data, *next_slot, logical as i32)?;
+            *next_slot += 1;
+            return Ok(());
+        }
+
+        Err(invalid_report(format!(
+            "usage {} is not part of the {:?} report {:?}",
+            usage,
+            self.kind(),
+            self.report_id()
+        )))
+    }
+
+    fn strip_report_id<'a>(&self, buf: &'a [u8]) -> HidResult<&'a [u8]> {
+        if self.buffer_len() == self.byte_len() {
+            return Ok(buf);
+        }
+
+        match buf.split_first() {
+            Some((&id, data)) if id == self.report_id().unwrap_or(0) => Ok(data),
+            Some((&id, _)) => Err(invalid_report(format!(
+                "expected report ID {}, got {}",
+                self.report_id().unwrap_or(0),
+                id
+            ))),
+            None => Err(HidError::InvalidZeroSizeData),
+        }
+    }
+}
+
+impl ReportLayout {
+    /// Decode an input report as returned by [`HidDevice::read()`](crate::HidDevice::read),
+    /// selecting the report by its report ID.
+    pub fn decode_input(&self, buf: &[u8]) -> HidResult<Vec<UsageValue>> {
+        let report_id = if self.uses_report_ids() {
+            match buf.first() {
+                Some(&id) => Some(id),
+                None => return Err(HidError::InvalidZeroSizeData),
+            }
+        } else {
+            None
+        };
+
+        match self.report(ReportKind::Input, report_id) {
+            Some(report) => report.decode(buf),
+            None => Err(invalid_report(format!(
+                "unknown input report {:?}",
+                report_id
+            ))),
+        }
+    }
+}
+
+fn invalid_report(message: String) -> HidError {
+    HidError::InvalidReport { message }
+}
+
+fn read_bits(data: &[u8], offset: usize, size: usize) -> Option<u32> {
+    if size == 0 || size > 32 || offset + size > data.len() * 8 {
+        return None;
+    }
+
+    let mut value = 0u32;
+    for i in 0..size {
+        let bit = offset + i;
+        if data[bit / 8] & (1 << (bit % 8)) != 0 {
+            value |= 1 << i;
+        }
+    }
+    Some(value)
+}
+
+fn write_bits(data: &mut [u8], offset: usize, size: usize, value: u32) -> HidResult<()> {
+    if offset + size > data.len() * 8 {
+        return Err(invalid_report(format!(
+            "field at bit {} does not fit into {} bytes",
+            offset,
+            data.len()
+        )));
+    }
+
+    for i in 0..size {
+        let bit = offset + i;
+        if value & (1 << i) != 0 {
+            data[bit / 8] |= 1 << (bit % 8);
+        } else {
+            data[bit / 8] &= !(1 << (bit % 8));
+        }
+    }
+    Ok(())
+}
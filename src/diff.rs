@@ -0,0 +1,151 @@
+//! Changes between two device lists, see [`HidApi::refresh_devices_diff()`].
+//! Also used to detect the devices, which were attached or detached, for
+//! [`HidApi::hotplug()`].
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CStr;
+
+use crate::{DeviceInfo, HidApi, HidResult, WcharString};
+
+/// The devices, which were added, removed or changed by
+/// [`HidApi::refresh_devices_diff()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceListDiff {
+    pub added: Vec<DeviceInfo>,
+    pub removed: Vec<DeviceInfo>,
+    /// Pairs of the previous and the current entry of a device, whose details,
+    /// like the product string, changed.
+    pub changed: Vec<(DeviceInfo, DeviceInfo)>,
+}
+
+impl DeviceListDiff {
+    /// Whether the device list is unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    pub(crate) fn new(old: &[DeviceInfo], new: &[DeviceInfo]) -> Self {
+        // Indices of the previous entries per key. Entries with the same key
+        // are matched in order, so every duplicate is matched at most once.
+        let mut unmatched: HashMap<Key<'_>, VecDeque<usize>> = HashMap::new();
+        for (index, info) in old.iter().enumerate() {
+            unmatched.entry(Key::of(info)).or_default().push_back(index);
+        }
+
+        let mut matched = vec![false; old.len()];
+        let mut diff = DeviceListDiff::default();
+        for info in new {
+            match unmatched
+                .get_mut(&Key::of(info))
+                .and_then(VecDeque::pop_front)
+            {
+                None => diff.added.push(info.clone()),
+                Some(index) => {
+                    matched[index] = true;
+                    if !same_details(&old[index], info) {
+                        diff.changed.push((old[index].clone(), info.clone()));
+                    }
+                }
+            }
+        }
+        // Keep the order of the previous list
+        diff.removed = old
+            .iter()
+            .zip(matched)
+            .filter(|&(_, matched)| !matched)
+            .map(|(info, _)| info.clone())
+            .collect();
+        diff
+    }
+}
+
+/// Identifies an entry across enumerations.
+///
+/// Depending on the backend, the entries of one device differ by path, by
+/// interface or only by the usage of their top level collection.
+#[derive(PartialEq, Eq, Hash)]
+enum Key<'a> {
+    Path {
+        path: &'a CStr,
+        usage_page: u16,
+        usage: u16,
+        interface_number: i32,
+    },
+    /// For entries without a path.
+    Ids {
+        vendor_id: u16,
+        product_id: u16,
+        serial_number: &'a WcharString,
+        interface_number: i32,
+        usage_page: u16,
+        usage: u16,
+    },
+}
+
+impl<'a> Key<'a> {
+    fn of(info: &'a DeviceInfo) -> Self {
+        if !info.path.as_bytes().is_empty() {
+            return Key::Path {
+                path: &info.path,
+                usage_page: info.usage_page,
+                usage: info.usage,
+                interface_number: info.interface_number,
+            };
+        }
+        Key::Ids {
+            vendor_id: info.vendor_id,
+            product_id: info.product_id,
+            serial_number: &info.serial_number,
+            interface_number: info.interface_number,
+            usage_page: info.usage_page,
+            usage: info.usage,
+        }
+    }
+}
+
+/// Compare all fields, unlike `DeviceInfo::eq()`, which only compares the
+/// identifying ones.
+fn same_details(a: &DeviceInfo, b: &DeviceInfo) -> bool {
+    a == b
+        && a.release_number == b.release_number
+        && a.manufacturer_string == b.manufacturer_string
+        && a.product_string == b.product_string
+        && a.bus_type == b.bus_type
+}
+
+impl HidApi {
+    /// Refresh the device list like [`HidApi::refresh_devices()`], and return
+    /// how it changed.
+    ///
+    /// Entries are matched by their path, interface number, usage page and
+    /// usage. Entries without a path are matched by vendor and product id and
+    /// serial number instead of the path. If several entries share all of
+    /// these, they are matched in the order of the list, and only the
+    /// surplus is reported as added or removed.
+    ///
+    /// ```
+    /// use hidapi::mock::{MockBackend, MockDevice};
+    /// use hidapi::HidApi;
+    ///
+    /// let backend = MockBackend::new();
+    /// let mut api = HidApi::with_backend(backend.clone()).unwrap();
+    ///
+    /// let device = MockDevice::new(0x1234, 0x5678);
+    /// backend.add_device(&device);
+    /// let diff = api.refresh_devices_diff().unwrap();
+    /// assert_eq!(diff.added.len(), 1);
+    /// assert!(diff.removed.is_empty() && diff.changed.is_empty());
+    ///
+    /// assert!(api.refresh_devices_diff().unwrap().is_empty());
+    ///
+    /// device.disconnect();
+    /// let diff = api.refresh_devices_diff().unwrap();
+    /// assert_eq!(diff.removed[0].product_id(), 0x5678);
+    /// ```
+    pub fn refresh_devices_diff(&mut self) -> HidResult<DeviceListDiff> {
+        let device_list = self.backend.get_hid_device_info_vector()?;
+        let diff = DeviceListDiff::new(&self.device_list, &device_list);
+        self.device_list = device_list;
+        Ok(diff)
+    }
+}
@@ -5,21 +5,54 @@
 // **************************************************************************
 
 use libc::wchar_t;
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
+use std::io;
 
 use crate::DeviceInfo;
 
 #[derive(Debug)]
 pub enum HidError {
-    HidApiError { message: String },
+    HidApiError {
+        message: String,
+    },
     HidApiErrorEmpty,
-    FromWideCharError { wide_char: wchar_t },
+    FromWideCharError {
+        wide_char: wchar_t,
+    },
     InitializationError,
+    InitializationModeMismatch {
+        enumerate: bool,
+    },
+    DevicesStillOpen {
+        open: usize,
+    },
     InvalidZeroSizeData,
-    IncompleteSendError { sent: usize, all: usize },
-    SetBlockingModeError { mode: &'static str },
-    OpenHidDeviceWithDeviceInfoError { device_info: Box<DeviceInfo> },
+    IncompleteSendError {
+        sent: usize,
+        all: usize,
+    },
+    SetBlockingModeError {
+        mode: &'static str,
+    },
+    OpenHidDeviceWithDeviceInfoError {
+        device_info: Box<DeviceInfo>,
+    },
+    IoError {
+        error: io::Error,
+    },
+    InvalidReportDescriptor {
+        offset: usize,
+        reason: Cow<'static, str>,
+    },
+    InvalidReport {
+        message: String,
+    },
+    TransactionTimedOut {
+        attempts: u32,
+    },
+    NotConnected,
 }
 
 impl Display for HidError {
@@ -33,6 +66,18 @@ impl Display for HidError {
             HidError::InitializationError => {
                 write!(f, "Failed to initialize hidapi")
             }
+            HidError::InitializationModeMismatch { enumerate } => write!(
+                f,
+                "hidapi is still in use with enumeration={}, can not initialize it in another mode",
+                enumerate
+            ),
+            HidError::DevicesStillOpen { open } => {
+                write!(
+                    f,
+                    "Can not shut down hidapi, {} devices are still open",
+                    open
+                )
+            }
             HidError::InvalidZeroSizeData => write!(f, "Invalid data: size can not be 0"),
             HidError::IncompleteSendError { sent, all } => write!(
                 f,
@@ -45,8 +90,202 @@ impl Display for HidError {
             HidError::OpenHidDeviceWithDeviceInfoError { device_info } => {
                 write!(f, "Can not open hid device with: {:?}", *device_info)
             }
+            HidError::IoError { error } => write!(f, "{}", error),
+            HidError::InvalidReportDescriptor { offset, reason } => write!(
+                f,
+                "Invalid report descriptor at byte {}: {}",
+                offset, reason
+            ),
+            HidError::InvalidReport { message } => write!(f, "Invalid report: {}", message),
+            HidError::TransactionTimedOut { attempts } => {
+                write!(f, "No matching reply received after {} attempts", attempts)
+            }
+            HidError::NotConnected => write!(f, "Device is not connected"),
+        }
+    }
+}
+
+impl Error for HidError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HidError::IoError { error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl HidError {
+    /// Classify the error.
+    ///
+    /// For the backends using the hidapi C library, this is a best effort:
+    /// their errors only carry a message, so they are classified by its
+    /// wording. This works for the common messages, but may return
+    /// [`ErrorKind::Other`] for others, or a wrong kind for unusual wordings.
+    /// Errors of the `linux-native-hidraw` backend are [`HidError::IoError`]
+    /// and classified by their `errno`, see [`HidError::raw_os_error()`].
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            HidError::HidApiError { message } => classify_message(message),
+            HidError::HidApiErrorEmpty
+            | HidError::InitializationError
+            | HidError::InitializationModeMismatch { .. } => ErrorKind::Other,
+            HidError::FromWideCharError { .. }
+            | HidError::InvalidZeroSizeData
+            | HidError::OpenHidDeviceWithDeviceInfoError { .. }
+            | HidError::InvalidReportDescriptor { .. }
+            | HidError::InvalidReport { .. } => ErrorKind::InvalidInput,
+            HidError::IncompleteSendError { .. } | HidError::SetBlockingModeError { .. } => {
+                ErrorKind::Io
+            }
+            HidError::DevicesStillOpen { .. } => ErrorKind::Busy,
+            HidError::TransactionTimedOut { .. } => ErrorKind::Timeout,
+            HidError::NotConnected => ErrorKind::Disconnected,
+            HidError::IoError { error } => classify_io_error(error),
+        }
+    }
+
+    /// The OS error code (`errno` on unix), if the backend reported one.
+    ///
+    /// Only [`HidError::IoError`] carries one, as returned by the
+    /// `linux-native-hidraw` backend. The hidapi C library does not expose
+    /// it.
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self {
+            HidError::IoError { error } => error.raw_os_error(),
+            _ => None,
         }
     }
 }
 
-impl Error for HidError {}
+/// A rough classification of a [`HidError`], see [`HidError::kind()`].
+///
+/// ```
+/// use hidapi::mock::{MockBackend, MockDevice};
+/// use hidapi::{ErrorKind, HidApi, HidError};
+/// use std::io;
+///
+/// let device = MockDevice::new(0x1234, 0x5678);
+/// let backend = MockBackend::new();
+/// backend.add_device(&device);
+/// let api = HidApi::with_backend(backend).unwrap();
+///
+/// let err = api.open(0x1234, 0x0000).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::NotFound);
+///
+/// let dev = api.open(0x1234, 0x5678).unwrap();
+/// device.disconnect();
+/// let err = dev.write(&[0x00, 0x01]).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Disconnected);
+///
+/// // Round trip through io::Error, e.g. for use in io::Read implementations
+/// let err = io::Error::from(err);
+/// assert_eq!(err.kind(), io::ErrorKind::NotConnected);
+/// assert_eq!(HidError::from(err).kind(), ErrorKind::Disconnected);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// No matching device was found.
+    NotFound,
+    /// The device exists, but the user lacks the permissions to open it.
+    PermissionDenied,
+    /// The device was unplugged or otherwise went away.
+    Disconnected,
+    /// An operation did not finish in time.
+    Timeout,
+    /// The device or the library is used by someone else.
+    Busy,
+    /// The arguments, like the report data, were rejected.
+    InvalidInput,
+    /// Any other failure while talking to the device.
+    Io,
+    /// Errors, which fit none of the other kinds.
+    Other,
+}
+
+impl From<ErrorKind> for io::ErrorKind {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::NotFound => io::ErrorKind::NotFound,
+            ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
+            ErrorKind::Disconnected => io::ErrorKind::NotConnected,
+            ErrorKind::Timeout => io::ErrorKind::TimedOut,
+            ErrorKind::Busy => io::ErrorKind::ResourceBusy,
+            ErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+            ErrorKind::Io | ErrorKind::Other => io::ErrorKind::Other,
+        }
+    }
+}
+
+pub(crate) fn classify_io_error(error: &io::Error) -> ErrorKind {
+    #[cfg(unix)]
+    match error.raw_os_error() {
+        Some(libc::ENODEV) | Some(libc::ENXIO) | Some(libc::ESHUTDOWN) | Some(libc::EPIPE) => {
+            return ErrorKind::Disconnected
+        }
+        Some(libc::EBUSY) => return ErrorKind::Busy,
+        _ => {}
+    }
+
+    match error.kind() {
+        io::ErrorKind::NotFound => ErrorKind::NotFound,
+        io::ErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+        io::ErrorKind::NotConnected | io::ErrorKind::BrokenPipe => ErrorKind::Disconnected,
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => ErrorKind::Timeout,
+        io::ErrorKind::ResourceBusy => ErrorKind::Busy,
+        io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => ErrorKind::InvalidInput,
+        _ => ErrorKind::Io,
+    }
+}
+
+/// Classify the messages of the hidapi C library and the rust backends.
+fn classify_message(message: &str) -> ErrorKind {
+    const PATTERNS: &[(&str, ErrorKind)] = &[
+        ("disconnected", ErrorKind::Disconnected),
+        ("no such device", ErrorKind::Disconnected),
+        ("device not configured", ErrorKind::Disconnected),
+        ("device is not connected", ErrorKind::Disconnected),
+        ("permission denied", ErrorKind::PermissionDenied),
+        ("access is denied", ErrorKind::PermissionDenied),
+        ("access denied", ErrorKind::PermissionDenied),
+        ("resource busy", ErrorKind::Busy),
+        ("resource is in use", ErrorKind::Busy),
+        ("timed out", ErrorKind::Timeout),
+        ("not found", ErrorKind::NotFound),
+        ("no hid devices", ErrorKind::NotFound),
+        ("no matching device", ErrorKind::NotFound),
+        ("no such file", ErrorKind::NotFound),
+        ("invalid argument", ErrorKind::InvalidInput),
+        ("the parameter is incorrect", ErrorKind::InvalidInput),
+    ];
+
+    let message = message.to_lowercase();
+    PATTERNS
+        .iter()
+        .find(|(pattern, _)| message.contains(pattern))
+        .map_or(ErrorKind::Other, |&(_, kind)| kind)
+}
+
+impl From<io::Error> for HidError {
+    /// Unwraps errors created from a [`HidError`], other errors are wrapped
+    /// as [`HidError::IoError`].
+    fn from(error: io::Error) -> Self {
+        if error.get_ref().is_some_and(|inner| inner.is::<HidError>()) {
+            let inner = error.into_inner().unwrap();
+            return *inner.downcast::<HidError>().unwrap();
+        }
+        HidError::IoError { error }
+    }
+}
+
+impl From<HidError> for io::Error {
+    /// Unwraps [`HidError::IoError`], other errors are wrapped with the
+    /// [`io::ErrorKind`] matching [`HidError::kind()`].
+    fn from(error: HidError) -> Self {
+        match error {
+            HidError::IoError { error } => error,
+            error => io::Error::new(error.kind().into(), error),
+        }
+    }
+}
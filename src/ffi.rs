@@ -9,7 +9,7 @@
 use libc::{c_char, c_int, c_uchar, c_ushort, c_void, intptr_t, size_t, wchar_t};
 type HidBusType = crate::BusType;
 pub type HidDevice = c_void;
-type LibusbContext = c_void;
+pub type LibusbContext = c_void;
 
 #[repr(C)]
 pub struct HidDeviceInfo {
@@ -63,6 +63,11 @@ extern "C" {
         data: *mut c_uchar,
         length: size_t,
     ) -> c_int;
+    pub fn hid_get_input_report(
+        device: *mut HidDevice,
+        data: *mut c_uchar,
+        length: size_t,
+    ) -> c_int;
     pub fn hid_close(device: *mut HidDevice);
     pub fn hid_get_manufacturer_string(
         device: *mut HidDevice,
@@ -86,9 +91,56 @@ extern "C" {
         string: *mut wchar_t,
         maxlen: size_t,
     ) -> c_int;
+    pub fn hid_get_report_descriptor(
+        device: *mut HidDevice,
+        buf: *mut c_uchar,
+        buf_size: size_t,
+    ) -> c_int;
     pub fn hid_error(device: *mut HidDevice) -> *const wchar_t;
 }
 
+// For documentation look at the libusb header file libusb.h
+#[cfg(libusb)]
+pub mod libusb {
+    use super::*;
+
+    pub const LIBUSB_CAP_HAS_HOTPLUG: u32 = 0x0101;
+    pub const LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED: c_int = 0x01;
+    pub const LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT: c_int = 0x02;
+    pub const LIBUSB_HOTPLUG_MATCH_ANY: c_int = -1;
+    pub const LIBUSB_ERROR_INTERRUPTED: c_int = -10;
+
+    pub type LibusbHotplugCallbackFn = extern "C" fn(
+        ctx: *mut LibusbContext,
+        device: *mut c_void,
+        event: c_int,
+        user_data: *mut c_void,
+    ) -> c_int;
+
+    extern "C" {
+        pub fn libusb_init(ctx: *mut *mut LibusbContext) -> c_int;
+        pub fn libusb_exit(ctx: *mut LibusbContext);
+        pub fn libusb_has_capability(capability: u32) -> c_int;
+        pub fn libusb_hotplug_register_callback(
+            ctx: *mut LibusbContext,
+            events: c_int,
+            flags: c_int,
+            vendor_id: c_int,
+            product_id: c_int,
+            dev_class: c_int,
+            cb_fn: LibusbHotplugCallbackFn,
+            user_data: *mut c_void,
+            callback_handle: *mut c_int,
+        ) -> c_int;
+        pub fn libusb_hotplug_deregister_callback(ctx: *mut LibusbContext, callback_handle: c_int);
+        pub fn libusb_handle_events_timeout_completed(
+            ctx: *mut LibusbContext,
+            tv: *mut libc::timeval,
+            completed: *mut c_int,
+        ) -> c_int;
+    }
+}
+
 // For documentation look at the corresponding C header file hidapi_darwin.h
 #[cfg(target_os = "macos")]
 pub mod macos {
@@ -0,0 +1,160 @@
+//! Selection of devices by their [`DeviceInfo`].
+
+use crate::{BusType, DeviceInfo};
+
+/// Selects devices by the properties of their [`DeviceInfo`].
+///
+/// All criteria have to match, criteria which are not set match any device.
+/// String criteria are patterns, in which `*` matches any number of
+/// characters and `?` matches a single character.
+///
+/// Vendor and product id are passed on to the backend, so enumerating devices
+/// of a single product is cheap, see [`HidApi::enumerate()`](crate::HidApi::enumerate).
+///
+/// ```
+/// use hidapi::mock::{MockBackend, MockDevice};
+/// use hidapi::{DeviceFilter, HidApi};
+///
+/// let backend = MockBackend::new();
+/// backend.add_device(&MockDevice::new(0x1234, 0x0001).with_serial_number("A-100"));
+/// backend.add_device(&MockDevice::new(0x1234, 0x0001).with_serial_number("B-200"));
+/// backend.add_device(&MockDevice::new(0x1234, 0x0002).with_serial_number("A-300"));
+///
+/// let api = HidApi::with_backend(backend).unwrap();
+/// let filter = DeviceFilter::new()
+///     .vendor_id(0x1234)
+///     .product_id(0x0001)
+///     .serial_number("A-*");
+/// let devices = api.enumerate(&filter).unwrap();
+/// assert_eq!(devices.len(), 1);
+/// assert_eq!(devices[0].serial_number(), Some("A-100"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DeviceFilter {
+    vendor_id: Option<u16>,
+    product_id: Option<u16>,
+    usage_page: Option<u16>,
+    usage: Option<u16>,
+    interface_number: Option<i32>,
+    bus_type: Option<BusType>,
+    serial_number: Option<String>,
+    product_string: Option<String>,
+}
+
+impl DeviceFilter {
+    /// Create a filter, which matches all devices.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vendor_id(mut self, vendor_id: u16) -> Self {
+        self.vendor_id = Some(vendor_id);
+        self
+    }
+
+    pub fn product_id(mut self, product_id: u16) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Not available on linux libusb backends, see [`DeviceInfo::usage_page()`].
+    #[cfg(not(all(libusb, target_os = "linux")))]
+    pub fn usage_page(mut self, usage_page: u16) -> Self {
+        self.usage_page = Some(usage_page);
+        self
+    }
+
+    /// Not available on linux libusb backends, see [`DeviceInfo::usage()`].
+    #[cfg(not(all(libusb, target_os = "linux")))]
+    pub fn usage(mut self, usage: u16) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    pub fn interface_number(mut self, interface_number: i32) -> Self {
+        self.interface_number = Some(interface_number);
+        self
+    }
+
+    pub fn bus_type(mut self, bus_type: BusType) -> Self {
+        self.bus_type = Some(bus_type);
+        self
+    }
+
+    /// Match the serial number against a pattern. Devices without a serial
+    /// number, or one which can not be converted to a `String`, never match.
+    pub fn serial_number(mut self, pattern: &str) -> Self {
+        self.serial_number = Some(pattern.to_owned());
+        self
+    }
+
+    /// Match the product string against a pattern. Devices without a product
+    /// string, or one which can not be converted to a `String`, never match.
+    pub fn product_string(mut self, pattern: &str) -> Self {
+        self.product_string = Some(pattern.to_owned());
+        self
+    }
+
+    /// The vendor and product id to pass to the backend, where 0 matches any id.
+    pub(crate) fn ids(&self) -> (u16, u16) {
+        (self.vendor_id.unwrap_or(0), self.product_id.unwrap_or(0))
+    }
+
+    /// Check whether a device matches all criteria of this filter.
+    pub fn matches(&self, info: &DeviceInfo) -> bool {
+        fn matches_value<T: PartialEq>(expected: Option<T>, actual: T) -> bool {
+            expected.is_none_or(|expected| expected == actual)
+        }
+
+        fn matches_pattern(pattern: &Option<String>, actual: Option<&str>) -> bool {
+            match (pattern, actual) {
+                (None, _) => true,
+                (Some(pattern), Some(actual)) => glob_match(pattern, actual),
+                (Some(_), None) => false,
+            }
+        }
+
+        matches_value(self.vendor_id, info.vendor_id)
+            && matches_value(self.product_id, info.product_id)
+            && matches_value(self.usage_page, info.usage_page)
+            && matches_value(self.usage, info.usage)
+            && matches_value(self.interface_number, info.interface_number)
+            && matches_value(self.bus_type, info.bus_type)
+            && matches_pattern(&self.serial_number, info.serial_number())
+            && matches_pattern(&self.product_string, info.product_string())
+    }
+}
+
+/// Match `text` against a pattern with the wildcards `*` and `?`.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it was tried with
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` match one more character
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
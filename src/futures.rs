@@ -0,0 +1,264 @@
+//! Executor agnostic async access to a [`HidDevice`].
+//!
+//! [`HidStream`] implements [`Stream`] for input reports and [`Sink`] for
+//! output reports, so it works with any executor. With the
+//! `linux-native-hidraw` backend, reads are driven by the readiness of the
+//! hidraw file descriptor through the `async-io` reactor. The other backends
+//! offer no pollable handle, so the reports are read on a thread, as with
+//! [`HidDevice::spawn_reader()`]. Writes always happen on a helper thread,
+//! because they block until the report has been sent.
+//!
+//! ```
+//! use futures::executor::block_on;
+//! use futures::{SinkExt, StreamExt};
+//! use hidapi::futures::HidStream;
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::HidApi;
+//!
+//! let mock = MockDevice::new(0x1234, 0x5678);
+//! let backend = MockBackend::new();
+//! backend.add_device(&mock);
+//!
+//! let api = HidApi::with_backend(backend).unwrap();
+//! let mut stream = HidStream::new(api.open(0x1234, 0x5678).unwrap()).unwrap();
+//!
+//! block_on(async {
+//!     stream.send([0x00, 0x01]).await.unwrap();
+//!     assert_eq!(mock.writes(), vec![vec![0x00, 0x01]]);
+//!
+//!     mock.push_input_report(&[0x02, 0x03]);
+//!     let report = stream.next().await.unwrap().unwrap();
+//!     assert_eq!(report, vec![0x02, 0x03]);
+//! });
+//! ```
+
+#[cfg(unix)]
+use std::os::unix::io::{AsFd, BorrowedFd, RawFd};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::task::{ready, Context, Poll, Waker};
+use std::thread;
+
+#[cfg(unix)]
+use async_io::Async;
+use futures_core::Stream;
+use futures_sink::Sink;
+
+use crate::reader::ReportThread;
+use crate::{HidDevice, HidError, HidReader, HidResult, HidWriter, ReaderConfig, MAX_REPORT_LEN};
+
+/// The file descriptor of the device, which stays open as long as the
+/// `Async` registration holding it.
+#[cfg(unix)]
+struct PollFd(RawFd);
+
+#[cfg(unix)]
+impl AsFd for PollFd {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        // `Reader::Fd` drops the registration before its `HidReader`
+        unsafe { BorrowedFd::borrow_raw(self.0) }
+    }
+}
+
+enum Reader {
+    #[cfg(unix)]
+    Fd {
+        fd: Async<PollFd>,
+        input: HidReader,
+    },
+    Thread(ReportThread),
+}
+
+#[derive(Default)]
+struct WriteState {
+    queued: Option<Vec<u8>>,
+    busy: bool,
+    error: Option<HidError>,
+    waker: Option<Waker>,
+    closed: bool,
+    started: bool,
+}
+
+/// The output report handed over to the writer thread.
+#[derive(Default)]
+struct WriteSlot {
+    state: Mutex<WriteState>,
+    report_queued: Condvar,
+}
+
+struct Shared {
+    reader: Reader,
+    /// Used by the writer thread only, so a pending write never holds up the
+    /// stream.
+    output: HidWriter,
+    writer: WriteSlot,
+}
+
+/// A [`HidDevice`] as [`Stream`] of input reports and [`Sink`] of output
+/// reports.
+///
+/// Every item of the stream is one report as returned by
+/// [`HidDevice::read()`]. The stream ends after the first error. Every item
+/// sent to the sink is written with [`HidDevice::write()`], so it has to start
+/// with the report ID. Use `StreamExt::split()` from the `futures` crate to
+/// read and write from different tasks.
+pub struct HidStream {
+    shared: Arc<Shared>,
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl HidStream {
+    /// Wrap a device for async use.
+    ///
+    /// If the backend offers no pollable file descriptor, a reader thread is
+    /// started, which reads reports until the `HidStream` is dropped or reading
+    /// fails.
+    pub fn new(device: HidDevice) -> HidResult<Self> {
+        #[cfg(unix)]
+        {
+            if let Some(fd) = device.inner.pollable_fd() {
+                let fd = Async::new_nonblocking(PollFd(fd))?;
+                let (input, output) = device.split();
+                return Ok(HidStream::with_reader(Reader::Fd { fd, input }, output));
+            }
+        }
+
+        let (input, output) = device.split();
+        let reports = ReportThread::spawn(input, ReaderConfig::new())?;
+        Ok(HidStream::with_reader(Reader::Thread(reports), output))
+    }
+
+    fn with_reader(reader: Reader, output: HidWriter) -> Self {
+        HidStream {
+            shared: Arc::new(Shared {
+                reader,
+                output,
+                writer: WriteSlot::default(),
+            }),
+            buf: vec![0u8; MAX_REPORT_LEN],
+            done: false,
+        }
+    }
+
+    fn poll_written(&self, cx: &mut Context<'_>) -> Poll<HidResult<()>> {
+        let mut state = self.shared.writer.state.lock().unwrap();
+        if let Some(err) = state.error.take() {
+            return Poll::Ready(Err(err));
+        }
+        if state.busy {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for HidStream {
+    fn drop(&mut self) {
+        self.shared.writer.state.lock().unwrap().closed = true;
+        self.shared.writer.report_queued.notify_one();
+    }
+}
+
+impl Stream for HidStream {
+    type Item = HidResult<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let res = match this.shared.reader {
+            #[cfg(unix)]
+            Reader::Fd { ref fd, ref input } => loop {
+                // Check for reports buffered by `HidDevice::transact()` first,
+                // those never wake up the reactor
+                match input.read_timeout(&mut this.buf, 0) {
+                    Ok(0) => {}
+                    res => break res.map(|len| this.buf[..len].to_vec()),
+                }
+                if let Err(err) = ready!(fd.poll_readable(cx)) {
+                    break Err(err.into());
+                }
+            },
+            Reader::Thread(ref reports) => match ready!(reports.poll_next(cx)) {
+                Some(res) => res.map(|report| report.data),
+                // The thread only stops after delivering an error, which
+                // ended the stream
+                None => return Poll::Ready(None),
+            },
+        };
+
+        this.done = res.is_err();
+        Poll::Ready(Some(res))
+    }
+}
+
+impl<T: AsRef<[u8]>> Sink<T> for HidStream {
+    type Error = HidError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<HidResult<()>> {
+        self.poll_written(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> HidResult<()> {
+        let mut state = self.shared.writer.state.lock().unwrap();
+        if !state.started {
+            let shared = Arc::downgrade(&self.shared);
+            thread::Builder::new()
+                .name("hidapi-writer".into())
+                .spawn(move || write_reports(shared))?;
+            state.started = true;
+        }
+
+        state.queued = Some(item.as_ref().to_vec());
+        state.busy = true;
+        self.shared.writer.report_queued.notify_one();
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<HidResult<()>> {
+        self.poll_written(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<HidResult<()>> {
+        self.poll_written(cx)
+    }
+}
+
+fn write_reports(shared: Weak<Shared>) {
+    loop {
+        let report = {
+            let shared = match shared.upgrade() {
+                Some(shared) => shared,
+                None => return,
+            };
+            let mut state = shared.writer.state.lock().unwrap();
+            loop {
+                if state.closed {
+                    return;
+                }
+                if let Some(report) = state.queued.take() {
+                    break report;
+                }
+                state = shared.writer.report_queued.wait(state).unwrap();
+            }
+        };
+
+        let res = match shared.upgrade() {
+            Some(shared) => shared.output.write(&report),
+            None => return,
+        };
+
+        if let Some(shared) = shared.upgrade() {
+            let mut state = shared.writer.state.lock().unwrap();
+            state.busy = false;
+            state.error = res.err();
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
@@ -0,0 +1,667 @@
+//! The default hidapi backend, which wraps the hidapi C library.
+
+use libc::{c_int, size_t, wchar_t};
+use std::ffi::CStr;
+#[cfg(libusb)]
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::ffi;
+use crate::{
+    DeviceInfo, HidApiBackend, HidDeviceBackend, HidError, HidResult, HotplugMonitor, WcharString,
+    MAX_REPORT_DESCRIPTOR_SIZE,
+};
+
+const STRING_BUF_LEN: usize = 128;
+
+struct InitState {
+    /// The mode hidapi is initialized in, `None` if it is not initialized.
+    enumerate: Option<bool>,
+    /// Number of [`HidApiLib`] instances alive.
+    contexts: usize,
+    /// Number of devices open, through any context.
+    devices: usize,
+    /// libusb offers no way to enable device discovery again, once it was
+    /// disabled.
+    #[cfg(libusb)]
+    discovery_disabled: bool,
+}
+
+static INIT_STATE: Mutex<InitState> = Mutex::new(InitState {
+    enumerate: None,
+    contexts: 0,
+    devices: 0,
+    #[cfg(libusb)]
+    discovery_disabled: false,
+});
+
+/// Keeps the hidapi C library initialized, while it exists.
+///
+/// Every [`HidApiLib`] and every device opened through it holds one. When the
+/// last one is dropped, `hid_exit()` is called.
+struct ContextRef {
+    device: bool,
+    /// Number of devices open through the [`HidApiLib`], shared by the
+    /// references of the context and its devices.
+    open_devices: Arc<AtomicUsize>,
+}
+
+impl ContextRef {
+    /// Another reference for a device opened with this context.
+    fn for_device(&self) -> ContextRef {
+        INIT_STATE.lock().unwrap().devices += 1;
+        self.open_devices.fetch_add(1, Ordering::Relaxed);
+        ContextRef {
+            device: true,
+            open_devices: self.open_devices.clone(),
+        }
+    }
+}
+
+impl Drop for ContextRef {
+    fn drop(&mut self) {
+        let mut init_state = INIT_STATE.lock().unwrap();
+        if self.device {
+            init_state.devices -= 1;
+            self.open_devices.fetch_sub(1, Ordering::Relaxed);
+        } else {
+            init_state.contexts -= 1;
+        }
+
+        if init_state.contexts == 0 && init_state.devices == 0 {
+            unsafe { ffi::hid_exit() };
+            init_state.enumerate = None;
+        }
+    }
+}
+
+fn lazy_init(do_enumerate: bool) -> HidResult<ContextRef> {
+    let mut init_state = INIT_STATE.lock().unwrap();
+
+    match init_state.enumerate {
+        Some(enumerate) if enumerate != do_enumerate => {
+            return Err(HidError::InitializationModeMismatch { enumerate });
+        }
+        Some(_) => {}
+        None => {
+            #[cfg(libusb)]
+            if do_enumerate && init_state.discovery_disabled {
+                return Err(HidError::InitializationModeMismatch { enumerate: false });
+            }
+
+            #[cfg(libusb)]
+            if !do_enumerate {
+                // Do not scan for devices in libusb_init()
+                // Must be set before calling it.
+                // This is needed on Android, where access to USB devices is limited
+                unsafe { ffi::libusb_set_option(std::ptr::null_mut(), 2) }
+                init_state.discovery_disabled = true;
+            }
+
+            // Initialize the HID
+            if unsafe { ffi::hid_init() } == -1 {
+                return Err(HidError::InitializationError);
+            }
+
+            #[cfg(all(target_os = "macos", feature = "macos-shared-device"))]
+            unsafe {
+                ffi::macos::hid_darwin_set_open_exclusive(0)
+            }
+
+            init_state.enumerate = Some(do_enumerate);
+        }
+    }
+
+    init_state.contexts += 1;
+    Ok(ContextRef {
+        device: false,
+        open_devices: Arc::new(AtomicUsize::new(0)),
+    })
+}
+
+/// Backend using the hidapi C library.
+pub struct HidApiLib {
+    context: ContextRef,
+}
+
+impl HidApiLib {
+    pub fn new(enumerate: bool) -> HidResult<Self> {
+        Ok(HidApiLib {
+            context: lazy_init(enumerate)?,
+        })
+    }
+}
+
+impl HidApiBackend for HidApiLib {
+    fn get_hid_device_info_vector(&self) -> HidResult<Vec<DeviceInfo>> {
+        self.enumerate(0, 0)
+    }
+
+    fn enumerate(&self, vendor_id: u16, product_id: u16) -> HidResult<Vec<DeviceInfo>> {
+        let mut device_vector = Vec::with_capacity(8);
+
+        unsafe {
+            let enumeration = ffi::hid_enumerate(vendor_id, product_id);
+            {
+                let mut current_device = enumeration;
+
+                while !current_device.is_null() {
+                    device_vector.push(conv_hid_device_info(current_device)?);
+                    current_device = (*current_device).next;
+                }
+            }
+
+            if !enumeration.is_null() {
+                ffi::hid_free_enumeration(enumeration);
+            }
+        }
+
+        Ok(device_vector)
+    }
+
+    fn open(&self, vid: u16, pid: u16) -> HidResult<Box<dyn HidDeviceBackend>> {
+        let device = unsafe { ffi::hid_open(vid, pid, std::ptr::null()) };
+
+        if device.is_null() {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(Box::new(HidDeviceLib {
+                _hid_device: device,
+                _context: self.context.for_device(),
+            }))
+        }
+    }
+
+    fn open_serial(&self, vid: u16, pid: u16, sn: &str) -> HidResult<Box<dyn HidDeviceBackend>> {
+        let mut chars = sn.chars().map(|c| c as wchar_t).collect::<Vec<_>>();
+        chars.push(0 as wchar_t);
+        let device = unsafe { ffi::hid_open(vid, pid, chars.as_ptr()) };
+        if device.is_null() {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(Box::new(HidDeviceLib {
+                _hid_device: device,
+                _context: self.context.for_device(),
+            }))
+        }
+    }
+
+    fn open_path(&self, device_path: &CStr) -> HidResult<Box<dyn HidDeviceBackend>> {
+        let device = unsafe { ffi::hid_open_path(device_path.as_ptr()) };
+
+        if device.is_null() {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(Box::new(HidDeviceLib {
+                _hid_device: device,
+                _context: self.context.for_device(),
+            }))
+        }
+    }
+
+    #[cfg(libusb)]
+    fn wrap_sys_device(
+        &self,
+        sys_dev: isize,
+        interface_num: i32,
+    ) -> HidResult<Box<dyn HidDeviceBackend>> {
+        let device = unsafe { ffi::hid_libusb_wrap_sys_device(sys_dev, interface_num) };
+
+        if device.is_null() {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(Box::new(HidDeviceLib {
+                _hid_device: device,
+                _context: self.context.for_device(),
+            }))
+        }
+    }
+
+    fn check_error(&self) -> HidResult<HidError> {
+        Ok(HidError::HidApiError {
+            message: unsafe {
+                match wchar_to_string(ffi::hid_error(std::ptr::null_mut())) {
+                    WcharString::String(s) => s,
+                    _ => return Err(HidError::HidApiErrorEmpty),
+                }
+            },
+        })
+    }
+
+    fn shutdown(&self) -> HidResult<()> {
+        match self.context.open_devices.load(Ordering::Relaxed) {
+            0 => Ok(()),
+            open => Err(HidError::DevicesStillOpen { open }),
+        }
+    }
+
+    #[cfg(all(target_os = "linux", not(libusb)))]
+    fn hotplug_monitor(&self) -> HidResult<Box<dyn HotplugMonitor>> {
+        Ok(Box::new(crate::linux_uevent::UeventMonitor::new()?))
+    }
+
+    #[cfg(libusb)]
+    fn hotplug_monitor(&self) -> HidResult<Box<dyn HotplugMonitor>> {
+        // With device discovery disabled, the context of the monitor would not
+        // see any device either, so only follow what enumeration reports.
+        if INIT_STATE.lock().unwrap().discovery_disabled {
+            return Ok(Box::<crate::hotplug::PollingMonitor>::default());
+        }
+
+        match LibusbMonitor::new()? {
+            Some(monitor) => Ok(Box::new(monitor)),
+            // Not all platforms supported by libusb have hotplug support
+            None => Ok(Box::<crate::hotplug::PollingMonitor>::default()),
+        }
+    }
+}
+
+/// Converts a pointer to a `*const wchar_t` to a WcharString.
+unsafe fn wchar_to_string(wstr: *const wchar_t) -> WcharString {
+    if wstr.is_null() {
+        return WcharString::None;
+    }
+
+    let mut char_vector: Vec<char> = Vec::with_capacity(8);
+    let mut raw_vector: Vec<wchar_t> = Vec::with_capacity(8);
+    let mut index: isize = 0;
+    let mut invalid_char = false;
+
+    let o = |i| *wstr.offset(i);
+
+    while o(index) != 0 {
+        use std::char;
+
+        raw_vector.push(*wstr.offset(index));
+
+        if !invalid_char {
+            if let Some(c) = char::from_u32(o(index) as u32) {
+                char_vector.push(c);
+            } else {
+                invalid_char = true;
+            }
+        }
+
+        index += 1;
+    }
+
+    if !invalid_char {
+        WcharString::String(char_vector.into_iter().collect())
+    } else {
+        WcharString::Raw(raw_vector)
+    }
+}
+
+/// Convert the CFFI `HidDeviceInfo` struct to a native `HidDeviceInfo` struct
+unsafe fn conv_hid_device_info(src: *mut ffi::HidDeviceInfo) -> HidResult<DeviceInfo> {
+    Ok(DeviceInfo {
+        path: CStr::from_ptr((*src).path).to_owned(),
+        vendor_id: (*src).vendor_id,
+        product_id: (*src).product_id,
+        serial_number: wchar_to_string((*src).serial_number),
+        release_number: (*src).release_number,
+        manufacturer_string: wchar_to_string((*src).manufacturer_string),
+        product_string: wchar_to_string((*src).product_string),
+        usage_page: (*src).usage_page,
+        usage: (*src).usage,
+        interface_number: (*src).interface_number,
+        bus_type: (*src).bus_type,
+    })
+}
+
+/// Handle to a device opened through the hidapi C library.
+pub struct HidDeviceLib {
+    _hid_device: *mut ffi::HidDevice,
+    _context: ContextRef,
+}
+
+unsafe impl Send for HidDeviceLib {}
+
+impl Drop for HidDeviceLib {
+    fn drop(&mut self) {
+        unsafe { ffi::hid_close(self._hid_device) }
+    }
+}
+
+impl HidDeviceLib {
+    /// Check size returned by other methods, if it's equal to -1 check for
+    /// error and return Error, otherwise return size as unsigned number
+    fn check_size(&self, res: i32) -> HidResult<usize> {
+        if res == -1 {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(res as usize)
+        }
+    }
+}
+
+impl HidDeviceBackend for HidDeviceLib {
+    fn check_error(&self) -> HidResult<HidError> {
+        Ok(HidError::HidApiError {
+            message: unsafe {
+                match wchar_to_string(ffi::hid_error(self._hid_device)) {
+                    WcharString::String(s) => s,
+                    _ => return Err(HidError::HidApiErrorEmpty),
+                }
+            },
+        })
+    }
+
+    fn write(&self, data: &[u8]) -> HidResult<usize> {
+        let res = unsafe { ffi::hid_write(self._hid_device, data.as_ptr(), data.len() as size_t) };
+        self.check_size(res)
+    }
+
+    fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let res = unsafe { ffi::hid_read(self._hid_device, buf.as_mut_ptr(), buf.len() as size_t) };
+        self.check_size(res)
+    }
+
+    fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
+        let res = unsafe {
+            ffi::hid_read_timeout(
+                self._hid_device,
+                buf.as_mut_ptr(),
+                buf.len() as size_t,
+                timeout,
+            )
+        };
+        self.check_size(res)
+    }
+
+    fn send_feature_report(&self, data: &[u8]) -> HidResult<usize> {
+        let res = unsafe {
+            ffi::hid_send_feature_report(self._hid_device, data.as_ptr(), data.len() as size_t)
+        };
+        self.check_size(res)
+    }
+
+    fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let res = unsafe {
+            ffi::hid_get_feature_report(self._hid_device, buf.as_mut_ptr(), buf.len() as size_t)
+        };
+        self.check_size(res)
+    }
+
+    fn get_input_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let res = unsafe {
+            ffi::hid_get_input_report(self._hid_device, buf.as_mut_ptr(), buf.len() as size_t)
+        };
+        self.check_size(res)
+    }
+
+    fn set_blocking_mode(&self, blocking: bool) -> HidResult<()> {
+        let res = unsafe {
+            ffi::hid_set_nonblocking(self._hid_device, if blocking { 0i32 } else { 1i32 })
+        };
+        if res == -1 {
+            Err(HidError::SetBlockingModeError {
+                mode: match blocking {
+                    true => "blocking",
+                    false => "not blocking",
+                },
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn get_manufacturer_string(&self) -> HidResult<Option<String>> {
+        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
+        let res = unsafe {
+            ffi::hid_get_manufacturer_string(
+                self._hid_device,
+                buf.as_mut_ptr(),
+                STRING_BUF_LEN as size_t,
+            )
+        };
+        let res = self.check_size(res)?;
+        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+    }
+
+    fn get_product_string(&self) -> HidResult<Option<String>> {
+        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
+        let res = unsafe {
+            ffi::hid_get_product_string(
+                self._hid_device,
+                buf.as_mut_ptr(),
+                STRING_BUF_LEN as size_t,
+            )
+        };
+        let res = self.check_size(res)?;
+        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+    }
+
+    fn get_serial_number_string(&self) -> HidResult<Option<String>> {
+        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
+        let res = unsafe {
+            ffi::hid_get_serial_number_string(
+                self._hid_device,
+                buf.as_mut_ptr(),
+                STRING_BUF_LEN as size_t,
+            )
+        };
+        let res = self.check_size(res)?;
+        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+    }
+
+    fn get_indexed_string(&self, index: i32) -> HidResult<Option<String>> {
+        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
+        let res = unsafe {
+            ffi::hid_get_indexed_string(
+                self._hid_device,
+                index as c_int,
+                buf.as_mut_ptr(),
+                STRING_BUF_LEN,
+            )
+        };
+        let res = self.check_size(res)?;
+        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+    }
+
+    fn get_device_info(&self) -> HidResult<DeviceInfo> {
+        let raw_device = unsafe { ffi::hid_get_device_info(self._hid_device) };
+        if raw_device.is_null() {
+            match self.check_error() {
+                Ok(err) | Err(err) => return Err(err),
+            }
+        }
+
+        unsafe { conv_hid_device_info(raw_device) }
+    }
+
+    fn get_report_descriptor(&self) -> HidResult<Vec<u8>> {
+        let mut buf = vec![0u8; MAX_REPORT_DESCRIPTOR_SIZE];
+        let res = unsafe {
+            ffi::hid_get_report_descriptor(self._hid_device, buf.as_mut_ptr(), buf.len() as size_t)
+        };
+        let res = self.check_size(res)?;
+        buf.truncate(res);
+        Ok(buf)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn get_location_id(&self) -> HidResult<u32> {
+        let mut location_id: u32 = 0;
+
+        let res = unsafe {
+            ffi::macos::hid_darwin_get_location_id(self._hid_device, &mut location_id as *mut u32)
+        };
+
+        if res == -1 {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(err) => Err(err),
+            }
+        } else {
+            Ok(location_id)
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn is_open_exclusive(&self) -> HidResult<bool> {
+        let res = unsafe { ffi::macos::hid_darwin_is_device_open_exclusive(self._hid_device) };
+
+        if res == -1 {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(err) => Err(err),
+            }
+        } else {
+            Ok(res == 1)
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn get_container_id(&self) -> HidResult<winapi::shared::guiddef::GUID> {
+        let mut container_id: winapi::shared::guiddef::GUID = unsafe { std::mem::zeroed() };
+
+        let res = unsafe {
+            ffi::windows::hid_winapi_get_container_id(
+                self._hid_device,
+                std::ptr::addr_of_mut!(container_id),
+            )
+        };
+
+        if res == -1 {
+            match self.check_error() {
+                Ok(err) => Err(err),
+                Err(err) => Err(err),
+            }
+        } else {
+            Ok(container_id)
+        }
+    }
+}
+
+/// Hotplug monitor using the hotplug callbacks of libusb.
+///
+/// hidapi does not expose its libusb context, so the monitor uses its own.
+#[cfg(libusb)]
+struct LibusbMonitor {
+    context: *mut ffi::LibusbContext,
+    callback: c_int,
+    changed: Box<AtomicBool>,
+}
+
+#[cfg(libusb)]
+unsafe impl Send for LibusbMonitor {}
+
+#[cfg(libusb)]
+extern "C" fn libusb_hotplug_callback(
+    _ctx: *mut ffi::LibusbContext,
+    _device: *mut libc::c_void,
+    _event: c_int,
+    user_data: *mut libc::c_void,
+) -> c_int {
+    let changed = unsafe { &*(user_data as *const AtomicBool) };
+    changed.store(true, Ordering::Relaxed);
+    // Keep the callback registered
+    0
+}
+
+#[cfg(libusb)]
+impl LibusbMonitor {
+    /// Returns `None` if libusb has no hotplug support on this platform.
+    fn new() -> HidResult<Option<Self>> {
+        use ffi::libusb::*;
+
+        if unsafe { libusb_has_capability(LIBUSB_CAP_HAS_HOTPLUG) } == 0 {
+            return Ok(None);
+        }
+
+        let mut context = std::ptr::null_mut();
+        let res = unsafe { libusb_init(&mut context) };
+        if res < 0 {
+            return Err(libusb_error("libusb_init", res));
+        }
+
+        // Only create the monitor once the callback is registered, as
+        // dropping it deregisters the callback
+        let changed = Box::new(AtomicBool::new(false));
+        let mut callback = 0;
+        let res = unsafe {
+            libusb_hotplug_register_callback(
+                context,
+                LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED | LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT,
+                0,
+                LIBUSB_HOTPLUG_MATCH_ANY,
+                LIBUSB_HOTPLUG_MATCH_ANY,
+                LIBUSB_HOTPLUG_MATCH_ANY,
+                libusb_hotplug_callback,
+                &*changed as *const AtomicBool as *mut libc::c_void,
+                &mut callback,
+            )
+        };
+        if res < 0 {
+            // `changed` is freed on return, after libusb is done with it
+            unsafe { libusb_exit(context) };
+            return Err(libusb_error("libusb_hotplug_register_callback", res));
+        }
+
+        Ok(Some(LibusbMonitor {
+            context,
+            callback,
+            changed,
+        }))
+    }
+}
+
+#[cfg(libusb)]
+impl HotplugMonitor for LibusbMonitor {
+    fn wait(&mut self, timeout: i32) -> HidResult<bool> {
+        use ffi::libusb::*;
+
+        if self.changed.swap(false, Ordering::Relaxed) {
+            return Ok(true);
+        }
+
+        // libusb has no infinite timeout, so wait in steps of one second
+        let timeout = if timeout < 0 { 1000 } else { timeout };
+        let mut tv = libc::timeval {
+            tv_sec: (timeout / 1000) as libc::time_t,
+            tv_usec: ((timeout % 1000) * 1000) as libc::suseconds_t,
+        };
+        let res = unsafe {
+            libusb_handle_events_timeout_completed(self.context, &mut tv, std::ptr::null_mut())
+        };
+        if res < 0 && res != LIBUSB_ERROR_INTERRUPTED {
+            return Err(libusb_error("libusb_handle_events_timeout_completed", res));
+        }
+
+        Ok(self.changed.swap(false, Ordering::Relaxed))
+    }
+}
+
+#[cfg(libusb)]
+impl Drop for LibusbMonitor {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::libusb::libusb_hotplug_deregister_callback(self.context, self.callback);
+            ffi::libusb::libusb_exit(self.context);
+        }
+    }
+}
+
+#[cfg(libusb)]
+fn libusb_error(function: &str, code: c_int) -> HidError {
+    HidError::HidApiError {
+        message: format!("{}: libusb error {}", function, code),
+    }
+}
@@ -0,0 +1,305 @@
+//! Notifications about devices being attached and detached.
+//!
+//! [`HidApi::hotplug()`] creates a [`HotplugBuilder`], which selects the
+//! devices of interest and starts a background thread watching for changes.
+//! The events are either passed to a callback or received through the
+//! [`HotplugEvents`] iterator.
+//!
+//! How changes are detected depends on the backend: the hidraw backends listen
+//! to the device events broadcast by udev, the libusb backends use the hotplug
+//! support of libusb. Every notification is confirmed by enumerating the
+//! devices again, so the events carry the same [`DeviceInfo`] as
+//! [`HidApi::device_list()`]. Backends without hotplug support re-enumerate
+//! the devices periodically, as do the libusb backends in "do not enumerate"
+//! mode, where libusb does not discover devices by itself.
+//!
+//! ```
+//! use hidapi::hotplug::HotplugEvent;
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::HidApi;
+//! use std::time::Duration;
+//!
+//! let backend = MockBackend::new();
+//! let api = HidApi::with_backend(backend.clone()).unwrap();
+//! let events = api.hotplug().vendor_id(0x1234).events().unwrap();
+//!
+//! let device = MockDevice::new(0x1234, 0x5678);
+//! backend.add_device(&device);
+//! match events.recv_timeout(Duration::from_secs(1)).unwrap() {
+//!     Ok(HotplugEvent::Arrived(info)) => assert_eq!(info.product_id(), 0x5678),
+//!     other => panic!("unexpected event {:?}", other),
+//! }
+//!
+//! device.disconnect();
+//! match events.recv_timeout(Duration::from_secs(1)).unwrap() {
+//!     Ok(HotplugEvent::Left(info)) => assert_eq!(info.product_id(), 0x5678),
+//!     other => panic!("unexpected event {:?}", other),
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::{DeviceFilter, DeviceInfo, DeviceListDiff, HidApiBackend, HidResult, HotplugMonitor};
+
+#[cfg(doc)]
+use crate::HidApi;
+
+/// Timeout of a single wait of the hotplug thread, after which it checks
+/// whether it should stop.
+const WAIT_SLICE_MS: i32 = 100;
+
+/// Interval in which backends without hotplug support re-enumerate devices.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A device was attached or detached.
+#[derive(Debug, Clone)]
+pub enum HotplugEvent {
+    Arrived(DeviceInfo),
+    Left(DeviceInfo),
+}
+
+impl HotplugEvent {
+    /// The device, which was attached or detached.
+    pub fn device(&self) -> &DeviceInfo {
+        match self {
+            HotplugEvent::Arrived(info) | HotplugEvent::Left(info) => info,
+        }
+    }
+}
+
+/// Selects the devices to watch, see the [module documentation](self).
+///
+/// Without any filter, events for all devices are reported.
+pub struct HotplugBuilder {
+    backend: Arc<dyn HidApiBackend>,
+    filter: DeviceFilter,
+    enumerate_existing: bool,
+}
+
+impl HotplugBuilder {
+    pub(crate) fn new(backend: Arc<dyn HidApiBackend>) -> Self {
+        HotplugBuilder {
+            backend,
+            filter: DeviceFilter::new(),
+            enumerate_existing: false,
+        }
+    }
+
+    /// Only report devices matching `filter`, replacing all criteria set
+    /// before.
+    pub fn filter(mut self, filter: DeviceFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn vendor_id(mut self, vendor_id: u16) -> Self {
+        self.filter = self.filter.vendor_id(vendor_id);
+        self
+    }
+
+    pub fn product_id(mut self, product_id: u16) -> Self {
+        self.filter = self.filter.product_id(product_id);
+        self
+    }
+
+    /// Only report devices with the given usage page and usage.
+    ///
+    /// Not available on linux libusb backends, see [`DeviceInfo::usage()`].
+    #[cfg(not(all(libusb, target_os = "linux")))]
+    pub fn usage(mut self, usage_page: u16, usage: u16) -> Self {
+        self.filter = self.filter.usage_page(usage_page).usage(usage);
+        self
+    }
+
+    /// Report devices, which are already attached, with an
+    /// [`HotplugEvent::Arrived`] event before any other event.
+    pub fn enumerate_existing(mut self, enumerate_existing: bool) -> Self {
+        self.enumerate_existing = enumerate_existing;
+        self
+    }
+
+    /// Start watching and pass every event to `callback`.
+    ///
+    /// The callback is called from a background thread, until the returned
+    /// [`HotplugRegistration`] is dropped. If watching fails, the error is
+    /// passed to the callback and no further events are reported.
+    pub fn register<F>(self, mut callback: F) -> HidResult<HotplugRegistration>
+    where
+        F: FnMut(HidResult<HotplugEvent>) + Send + 'static,
+    {
+        // Start the monitor before the first enumeration, so no change in
+        // between is missed.
+        let mut monitor = self.backend.hotplug_monitor()?;
+        let mut known = self.matching_devices()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread_stop = stop.clone();
+        let thread = thread::Builder::new()
+            .name("hidapi-hotplug".into())
+            .spawn(move || {
+                if self.enumerate_existing {
+                    for info in &known {
+                        callback(Ok(HotplugEvent::Arrived(info.clone())));
+                    }
+                }
+
+                while !thread_stop.load(Ordering::Relaxed) {
+                    match monitor.wait(WAIT_SLICE_MS) {
+                        Ok(false) => continue,
+                        Ok(true) => {}
+                        Err(err) => return callback(Err(err)),
+                    }
+
+                    let devices = match self.matching_devices() {
+                        Ok(devices) => devices,
+                        Err(err) => return callback(Err(err)),
+                    };
+                    for event in diff(&known, &devices) {
+                        if thread_stop.load(Ordering::Relaxed) {
+                            return;
+                        }
+                        callback(Ok(event));
+                    }
+                    known = devices;
+                }
+            })?;
+
+        Ok(HotplugRegistration {
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Start watching and receive the events through an iterator.
+    pub fn events(self) -> HidResult<HotplugEvents> {
+        let (sender, receiver) = mpsc::channel();
+        let registration = self.register(move |event| {
+            // The receiver is only dropped together with the registration
+            let _ = sender.send(event);
+        })?;
+
+        Ok(HotplugEvents {
+            receiver,
+            _registration: registration,
+        })
+    }
+
+    fn matching_devices(&self) -> HidResult<Vec<DeviceInfo>> {
+        let (vendor_id, product_id) = self.filter.ids();
+        let mut devices = self.backend.enumerate(vendor_id, product_id)?;
+        devices.retain(|info| self.filter.matches(info));
+        Ok(devices)
+    }
+}
+
+fn diff(old: &[DeviceInfo], new: &[DeviceInfo]) -> Vec<HotplugEvent> {
+    let diff = DeviceListDiff::new(old, new);
+    let left = diff.removed.into_iter().map(HotplugEvent::Left);
+    let arrived = diff.added.into_iter().map(HotplugEvent::Arrived);
+    left.chain(arrived).collect()
+}
+
+/// Keeps a hotplug callback registered, until it is dropped.
+pub struct HotplugRegistration {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl HotplugRegistration {
+    /// Stop watching. Equivalent to dropping the registration.
+    pub fn unregister(self) {}
+}
+
+impl Drop for HotplugRegistration {
+    /// Waits for the hotplug thread to stop, so the callback is not called
+    /// anymore, unless it is dropped from within the callback.
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            if thread.thread().id() != thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Iterator over hotplug events, see [`HotplugBuilder::events()`].
+///
+/// The iterator blocks until the next event. It ends after an error.
+pub struct HotplugEvents {
+    receiver: mpsc::Receiver<HidResult<HotplugEvent>>,
+    _registration: HotplugRegistration,
+}
+
+impl HotplugEvents {
+    /// Returns the next event without blocking.
+    ///
+    /// Fails with [`mpsc::TryRecvError::Empty`] if there is no event right
+    /// now, and with [`mpsc::TryRecvError::Disconnected`] once the iterator
+    /// has ended.
+    pub fn try_next(&self) -> Result<HidResult<HotplugEvent>, mpsc::TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    /// Waits up to `timeout` for the next event.
+    ///
+    /// Fails with [`mpsc::RecvTimeoutError::Timeout`] if no event arrived in
+    /// time, and with [`mpsc::RecvTimeoutError::Disconnected`] once the
+    /// iterator has ended.
+    pub fn recv_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<HidResult<HotplugEvent>, mpsc::RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+}
+
+impl Iterator for HotplugEvents {
+    type Item = HidResult<HotplugEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+/// Monitor for backends without hotplug support, which reports a possible
+/// change once per `interval`.
+pub(crate) struct PollingMonitor {
+    interval: Duration,
+    next: Instant,
+}
+
+impl PollingMonitor {
+    pub(crate) fn new(interval: Duration) -> Self {
+        PollingMonitor {
+            interval,
+            next: Instant::now() + interval,
+        }
+    }
+}
+
+impl Default for PollingMonitor {
+    fn default() -> Self {
+        PollingMonitor::new(POLL_INTERVAL)
+    }
+}
+
+impl HotplugMonitor for PollingMonitor {
+    fn wait(&mut self, timeout: i32) -> HidResult<bool> {
+        let now = Instant::now();
+        if now < self.next {
+            let remaining = self.next - now;
+            if timeout >= 0 && Duration::from_millis(timeout as u64) < remaining {
+                thread::sleep(Duration::from_millis(timeout as u64));
+                return Ok(false);
+            }
+            thread::sleep(remaining);
+        }
+
+        self.next = Instant::now() + self.interval;
+        Ok(true)
+    }
+}
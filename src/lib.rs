@@ -41,28 +41,70 @@
 //! - `linux-static-hidraw`: uses statically linked `hidraw` backend on Linux (default)
 //! - `linux-shared-libusb`: uses dynamically linked `libusb` backend on Linux
 //! - `linux-shared-hidraw`: uses dynamically linked `hidraw` backend on Linux
+//! - `linux-native-hidraw`: uses a pure rust `hidraw` backend on Linux, without the hidapi C library
 //! - `illumos-static-libusb`: uses statically linked `libusb` backend on Illumos (default)
 //! - `illumos-shared-libusb`: uses statically linked `hidraw` backend on Illumos
 //! - `macos-shared-device`: enables shared access to HID devices on MacOS
+//! - `tokio`: async device access for the tokio runtime, see the [`tokio`](crate::tokio) module
+//! - `futures`: executor agnostic `Stream` and `Sink` for devices, see the [`futures`](crate::futures) module
+//! - `serde`: `Serialize` and `Deserialize` for [`DeviceInfo`], [`BusType`], [`HidError`] and [`ErrorKind`]
 //!
 //! ## Linux backends
 //!
 //! On linux the libusb backends do not support [`DeviceInfo::usage()`] and [`DeviceInfo::usage_page()`].
 //! The hidraw backend has support for them, but it might be buggy in older kernel versions.
 //!
+//! The `linux-native-hidraw` backend talks to `/dev/hidraw*` and sysfs directly from rust.
+//! It does not need a C compiler, libudev or pkg-config at build time, which makes it
+//! convenient for cross compilation. As with the other backends, exactly one linux backend
+//! must be enabled, so default features have to be disabled to use it.
+//!
 //! ## MacOS Shared device access
 //!
 //! Since `hidapi` 0.12 it is possible to open MacOS devices with shared access, so that multiple
 //! [`HidDevice`] handles can access the same physical device. For backward compatibility this is
 //! an opt-in that can be enabled with the `macos-shared-device` feature flag.
+//!
+//! ## Testing without hardware
+//!
+//! [`HidApi::with_backend()`] accepts any [`HidApiBackend`] implementation. The [`mock`]
+//! module contains one, which serves virtual devices with scripted reports.
 
 extern crate libc;
 
 #[cfg(target_os = "windows")]
 extern crate winapi;
 
+mod backend;
+pub mod descriptor;
+mod diff;
 mod error;
+#[cfg(hidapi)]
 mod ffi;
+mod filter;
+#[cfg(feature = "futures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures")))]
+pub mod futures;
+#[cfg(hidapi)]
+mod hidapi;
+pub mod hotplug;
+#[cfg(linux_native)]
+mod linux_native;
+#[cfg(all(target_os = "linux", not(libusb)))]
+mod linux_uevent;
+pub mod mock;
+mod poll;
+mod reader;
+pub mod reconnect;
+pub mod report;
+#[cfg(feature = "serde")]
+mod serialize;
+mod split;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub mod tokio;
+mod transact;
+pub mod usage_tables;
 
 #[cfg(target_os = "macos")]
 #[cfg_attr(docsrs, doc(cfg(target_os = "macos")))]
@@ -71,73 +113,61 @@ mod macos;
 #[cfg_attr(docsrs, doc(cfg(target_os = "windows")))]
 mod windows;
 
-use libc::{c_int, size_t, wchar_t};
+use libc::wchar_t;
+use std::collections::VecDeque;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::fmt;
 use std::fmt::Debug;
-use std::sync::Mutex;
-
-pub use error::HidError;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub use backend::{HidApiBackend, HidDeviceBackend, HotplugMonitor, SyncDeviceBackend};
+pub use diff::DeviceListDiff;
+pub use error::{ErrorKind, HidError};
+pub use filter::DeviceFilter;
+pub use poll::InputReportPoller;
+pub use reader::{BackgroundReader, OverflowPolicy, ReaderConfig, TimestampedReport};
+pub use split::{HidReader, HidWriter};
+pub use transact::RetryPolicy;
+
+#[cfg(hidapi)]
+use hidapi::HidApiLib as PlatformBackend;
+#[cfg(linux_native)]
+use linux_native::HidrawApi as PlatformBackend;
 
 pub type HidResult<T> = Result<T, HidError>;
 
-const STRING_BUF_LEN: usize = 128;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum InitState {
-    NotInit,
-    Init { enumerate: bool },
-}
-
-static INIT_STATE: Mutex<InitState> = Mutex::new(InitState::NotInit);
-
-fn lazy_init(do_enumerate: bool) -> HidResult<()> {
-    let mut init_state = INIT_STATE.lock().unwrap();
-
-    match *init_state {
-        InitState::NotInit => {
-            #[cfg(libusb)]
-            if !do_enumerate {
-                // Do not scan for devices in libusb_init()
-                // Must be set before calling it.
-                // This is needed on Android, where access to USB devices is limited
-                unsafe { ffi::libusb_set_option(std::ptr::null_mut(), 2) }
-            }
-
-            // Initialize the HID
-            if unsafe { ffi::hid_init() } == -1 {
-                return Err(HidError::InitializationError);
-            }
-
-            #[cfg(all(target_os = "macos", feature = "macos-shared-device"))]
-            unsafe {
-                ffi::macos::hid_darwin_set_open_exclusive(0)
-            }
-
-            *init_state = InitState::Init {
-                enumerate: do_enumerate,
-            }
-        }
-        InitState::Init { enumerate } => {
-            if enumerate != do_enumerate {
-                panic!("Trying to initialize hidapi with enumeration={}, but it is already initialized with enumeration={}.", do_enumerate, enumerate)
-            }
-        }
-    }
+/// Maximum size of a report descriptor, as defined by the USB HID specification.
+pub const MAX_REPORT_DESCRIPTOR_SIZE: usize = 4096;
 
-    Ok(())
-}
+/// Size of a buffer which fits any report including its report ID, the largest
+/// report size supported by linux.
+pub(crate) const MAX_REPORT_LEN: usize = 4096;
 
 /// `hidapi` context.
 ///
 /// The `hidapi` C library is lazily initialized when creating the first instance,
-/// and never deinitialized. Therefore, it is allowed to create multiple `HidApi`
-/// instances.
+/// and deinitialized when the last instance and all devices opened through it
+/// are dropped. Therefore, it is allowed to create multiple `HidApi` instances.
 ///
 /// Each instance has its own device list cache.
+///
+/// All operations are forwarded to a [`HidApiBackend`], which is the backend
+/// selected with the crate feature flags, unless another one is passed to
+/// [`HidApi::with_backend()`].
 pub struct HidApi {
     device_list: Vec<DeviceInfo>,
+    backend: Arc<dyn HidApiBackend>,
+}
+
+impl Debug for HidApi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HidApi").finish()
+    }
 }
 
 impl HidApi {
@@ -145,62 +175,82 @@ impl HidApi {
     ///
     /// Will also initialize the currently available device list.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if hidapi is already initialized in "without enumerate" mode
-    /// (i.e. if `new_without_enumerate()` has been called before).
+    /// Fails with [`HidError::InitializationModeMismatch`] if hidapi is in use in
+    /// "without enumerate" mode (i.e. if a context created with
+    /// `new_without_enumerate()`, or a device opened through it, is still
+    /// alive). With the libusb backends, the mode can not be switched back to
+    /// "do enumerate" at all, once "without enumerate" mode was used.
     pub fn new() -> HidResult<Self> {
-        lazy_init(true)?;
-
-        let device_list = unsafe { HidApi::get_hid_device_info_vector()? };
-
-        Ok(HidApi {
-            device_list: device_list.clone(),
-        })
+        HidApi::with_backend(PlatformBackend::new(true)?)
     }
 
     /// Create a new hidapi context, in "do not enumerate" mode.
     ///
     /// This is needed on Android, where access to USB device enumeration is limited.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if hidapi is already initialized in "do enumerate" mode
-    /// (i.e. if `new()` has been called before).
+    /// Fails with [`HidError::InitializationModeMismatch`] if hidapi is in use in
+    /// "do enumerate" mode (i.e. if a context created with `new()`, or a device
+    /// opened through it, is still alive).
     pub fn new_without_enumerate() -> HidResult<Self> {
-        lazy_init(false)?;
-
         Ok(HidApi {
             device_list: Vec::new(),
+            backend: Arc::new(PlatformBackend::new(false)?),
+        })
+    }
+
+    /// Create a new context, which uses `backend` instead of the backend
+    /// selected with the crate feature flags.
+    ///
+    /// Will also initialize the currently available device list. See the
+    /// [`mock`] module for a backend, which can be used in tests.
+    pub fn with_backend<B: HidApiBackend + 'static>(backend: B) -> HidResult<Self> {
+        let device_list = backend.get_hid_device_info_vector()?;
+
+        Ok(HidApi {
+            device_list,
+            backend: Arc::new(backend),
         })
     }
 
     /// Refresh devices list and information about them (to access them use
     /// `device_list()` method)
+    ///
+    /// See [`HidApi::refresh_devices_diff()`] to find out what changed.
     pub fn refresh_devices(&mut self) -> HidResult<()> {
-        let device_list = unsafe { HidApi::get_hid_device_info_vector()? };
+        let device_list = self.backend.get_hid_device_info_vector()?;
         self.device_list = device_list.clone();
         Ok(())
     }
 
-    unsafe fn get_hid_device_info_vector() -> HidResult<Vec<DeviceInfo>> {
-        let mut device_vector = Vec::with_capacity(8);
-
-        let enumeration = ffi::hid_enumerate(0, 0);
-        {
-            let mut current_device = enumeration;
-
-            while !current_device.is_null() {
-                device_vector.push(conv_hid_device_info(current_device)?);
-                current_device = (*current_device).next;
-            }
-        }
+    /// Returns information about the devices matching `filter`.
+    ///
+    /// Unlike [`HidApi::refresh_devices()`], this does not update the cached
+    /// device list. The vendor and product id of the filter are passed on to the
+    /// backend, so only matching devices are queried for their details.
+    pub fn enumerate(&self, filter: &DeviceFilter) -> HidResult<Vec<DeviceInfo>> {
+        let (vendor_id, product_id) = filter.ids();
+        let mut devices = self.backend.enumerate(vendor_id, product_id)?;
+        devices.retain(|info| filter.matches(info));
+        Ok(devices)
+    }
 
-        if !enumeration.is_null() {
-            ffi::hid_free_enumeration(enumeration);
-        }
+    /// Watch for devices being attached or detached.
+    ///
+    /// See the [`hotplug`] module for details.
+    pub fn hotplug(&self) -> hotplug::HotplugBuilder {
+        hotplug::HotplugBuilder::new(self.backend.clone())
+    }
 
-        Ok(device_vector)
+    /// Create a device handle, which reopens the device after it was
+    /// replugged.
+    ///
+    /// See the [`reconnect`] module for details.
+    pub fn reconnecting(&self, selector: reconnect::DeviceSelector) -> reconnect::ReconnectBuilder {
+        reconnect::ReconnectBuilder::new(self.backend.clone(), selector)
     }
 
     /// Returns iterator containing information about attached HID devices.
@@ -214,70 +264,45 @@ impl HidApi {
     /// first one found in the internal device list will be used. There are however
     /// no guarantees, which device this will be.
     pub fn open(&self, vid: u16, pid: u16) -> HidResult<HidDevice> {
-        let device = unsafe { ffi::hid_open(vid, pid, std::ptr::null()) };
-
-        if device.is_null() {
-            match self.check_error() {
-                Ok(err) => Err(err),
-                Err(e) => Err(e),
-            }
-        } else {
-            Ok(HidDevice {
-                _hid_device: device,
-            })
-        }
+        let inner = self.backend.open(vid, pid)?;
+        Ok(HidDevice::new(inner))
     }
 
     /// Open a HID device using a Vendor ID (VID), Product ID (PID) and
     /// a serial number.
     pub fn open_serial(&self, vid: u16, pid: u16, sn: &str) -> HidResult<HidDevice> {
-        let mut chars = sn.chars().map(|c| c as wchar_t).collect::<Vec<_>>();
-        chars.push(0 as wchar_t);
-        let device = unsafe { ffi::hid_open(vid, pid, chars.as_ptr()) };
-        if device.is_null() {
-            match self.check_error() {
-                Ok(err) => Err(err),
-                Err(e) => Err(e),
-            }
-        } else {
-            Ok(HidDevice {
-                _hid_device: device,
-            })
-        }
+        let inner = self.backend.open_serial(vid, pid, sn)?;
+        Ok(HidDevice::new(inner))
     }
 
     /// The path name be determined by inspecting the device list available with [HidApi::devices()](struct.HidApi.html#method.devices)
     ///
     /// Alternatively a platform-specific path name can be used (eg: /dev/hidraw0 on Linux).
     pub fn open_path(&self, device_path: &CStr) -> HidResult<HidDevice> {
-        let device = unsafe { ffi::hid_open_path(device_path.as_ptr()) };
-
-        if device.is_null() {
-            match self.check_error() {
-                Ok(err) => Err(err),
-                Err(e) => Err(e),
-            }
-        } else {
-            Ok(HidDevice {
-                _hid_device: device,
-            })
-        }
+        let inner = self.backend.open_path(device_path)?;
+        Ok(HidDevice::new(inner))
     }
 
     /// Open a HID device using libusb_wrap_sys_device.
     #[cfg(libusb)]
     pub fn wrap_sys_device(&self, sys_dev: isize, interface_num: i32) -> HidResult<HidDevice> {
-        let device = unsafe { ffi::hid_libusb_wrap_sys_device(sys_dev, interface_num) };
+        let inner = self.backend.wrap_sys_device(sys_dev, interface_num)?;
+        Ok(HidDevice::new(inner))
+    }
 
-        if device.is_null() {
-            match self.check_error() {
-                Ok(err) => Err(err),
-                Err(e) => Err(e),
-            }
-        } else {
-            Ok(HidDevice {
-                _hid_device: device,
-            })
+    /// Drop this context, checking that the library can be deinitialized.
+    ///
+    /// Fails with [`HidError::DevicesStillOpen`] if devices opened through
+    /// this context are still open, which keep the `hidapi` C library
+    /// initialized until they are dropped. The context is returned together
+    /// with the error, so shutting down can be retried after closing them.
+    /// Otherwise the library is deinitialized right away, unless other `HidApi`
+    /// instances, their devices or [hotplug registrations](HidApi::hotplug)
+    /// are alive.
+    pub fn shutdown(self) -> Result<(), (HidApi, HidError)> {
+        match self.backend.shutdown() {
+            Ok(()) => Ok(()),
+            Err(err) => Err((self, err)),
         }
     }
 
@@ -290,80 +315,31 @@ impl HidApi {
     /// library failed. The contained [HidError](enum.HidError.html) is the cause, why no error could
     /// be fetched.
     pub fn check_error(&self) -> HidResult<HidError> {
-        Ok(HidError::HidApiError {
-            message: unsafe {
-                match wchar_to_string(ffi::hid_error(std::ptr::null_mut())) {
-                    WcharString::String(s) => s,
-                    _ => return Err(HidError::HidApiErrorEmpty),
-                }
-            },
-        })
-    }
-}
-
-/// Converts a pointer to a `*const wchar_t` to a WcharString.
-unsafe fn wchar_to_string(wstr: *const wchar_t) -> WcharString {
-    if wstr.is_null() {
-        return WcharString::None;
-    }
-
-    let mut char_vector: Vec<char> = Vec::with_capacity(8);
-    let mut raw_vector: Vec<wchar_t> = Vec::with_capacity(8);
-    let mut index: isize = 0;
-    let mut invalid_char = false;
-
-    let o = |i| *wstr.offset(i);
-
-    while o(index) != 0 {
-        use std::char;
-
-        raw_vector.push(*wstr.offset(index));
-
-        if !invalid_char {
-            if let Some(c) = char::from_u32(o(index) as u32) {
-                char_vector.push(c);
-            } else {
-                invalid_char = true;
-            }
-        }
-
-        index += 1;
+        self.backend.check_error()
     }
-
-    if !invalid_char {
-        WcharString::String(char_vector.into_iter().collect())
-    } else {
-        WcharString::Raw(raw_vector)
-    }
-}
-
-/// Convert the CFFI `HidDeviceInfo` struct to a native `HidDeviceInfo` struct
-unsafe fn conv_hid_device_info(src: *mut ffi::HidDeviceInfo) -> HidResult<DeviceInfo> {
-    Ok(DeviceInfo {
-        path: CStr::from_ptr((*src).path).to_owned(),
-        vendor_id: (*src).vendor_id,
-        product_id: (*src).product_id,
-        serial_number: wchar_to_string((*src).serial_number),
-        release_number: (*src).release_number,
-        manufacturer_string: wchar_to_string((*src).manufacturer_string),
-        product_string: wchar_to_string((*src).product_string),
-        usage_page: (*src).usage_page,
-        usage: (*src).usage,
-        interface_number: (*src).interface_number,
-        bus_type: (*src).bus_type,
-    })
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(linux_native, allow(dead_code))]
 enum WcharString {
     String(String),
     Raw(Vec<wchar_t>),
     None,
 }
 
-impl Into<Option<String>> for WcharString {
-    fn into(self) -> Option<String> {
+impl Debug for WcharString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            WcharString::String(s) => write!(f, "{:?}", s),
+            WcharString::Raw(raw) => write!(f, "Raw({:?})", raw),
+            WcharString::None => write!(f, "None"),
+        }
+    }
+}
+
+impl From<WcharString> for Option<String> {
+    fn from(val: WcharString) -> Self {
+        match val {
             WcharString::String(s) => Some(s),
             _ => None,
         }
@@ -372,7 +348,8 @@ impl Into<Option<String>> for WcharString {
 
 /// The underlying HID bus type.
 #[repr(C)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BusType {
     Unknown = 0x00,
     Usb = 0x01,
@@ -381,11 +358,51 @@ pub enum BusType {
     Spi = 0x04,
 }
 
+impl fmt::Display for BusType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BusType::Unknown => "unknown",
+            BusType::Usb => "usb",
+            BusType::Bluetooth => "bluetooth",
+            BusType::I2c => "i2c",
+            BusType::Spi => "spi",
+        })
+    }
+}
+
 /// Device information. Use accessors to extract information about Hid devices.
 ///
 /// Note: Methods like `serial_number()` may return None, if the conversion to a
 /// String failed internally. You can however access the raw hid representation of the
 /// string by calling `serial_number_raw()`
+///
+/// Two `DeviceInfo` are equal, if they describe the same interface of the same
+/// device: path, vendor and product id, serial number, usage page, usage and
+/// interface number have to be equal. Release number, manufacturer and product
+/// string and bus type are not compared.
+///
+/// The `Display` form is a compact one line summary, while `Debug` shows all
+/// fields:
+///
+/// ```
+/// use hidapi::mock::{MockBackend, MockDevice};
+/// use hidapi::HidApi;
+/// use std::collections::HashSet;
+///
+/// let backend = MockBackend::new();
+/// backend.add_device(
+///     &MockDevice::new(0x046d, 0x0aaa)
+///         .with_path("/dev/hidraw3")
+///         .with_product_string("PRO X")
+///         .with_interface_number(2),
+/// );
+/// let api = HidApi::with_backend(backend).unwrap();
+/// let info = api.device_list().next().unwrap();
+/// assert_eq!(info.to_string(), "046d:0aaa \"PRO X\" if2 usb /dev/hidraw3");
+///
+/// let devices: HashSet<_> = api.device_list().cloned().collect();
+/// assert!(devices.contains(info));
+/// ```
 #[derive(Clone)]
 pub struct DeviceInfo {
     path: CString,
@@ -507,45 +524,266 @@ impl DeviceInfo {
     }
 }
 
+impl DeviceInfo {
+    /// The fields, which identify a device, see [`DeviceInfo`].
+    fn identity(&self) -> impl PartialEq + Hash + '_ {
+        (
+            &self.path,
+            self.vendor_id,
+            self.product_id,
+            &self.serial_number,
+            self.usage_page,
+            self.usage,
+            self.interface_number,
+        )
+    }
+}
+
+impl PartialEq for DeviceInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl Eq for DeviceInfo {}
+
+impl Hash for DeviceInfo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state)
+    }
+}
+
 impl fmt::Debug for DeviceInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("HidDeviceInfo")
-            .field("vendor_id", &self.vendor_id)
-            .field("product_id", &self.product_id)
+            .field("path", &self.path)
+            .field("vendor_id", &format_args!("{:#06x}", self.vendor_id))
+            .field("product_id", &format_args!("{:#06x}", self.product_id))
+            .field("serial_number", &self.serial_number)
+            .field(
+                "release_number",
+                &format_args!("{:#06x}", self.release_number),
+            )
+            .field("manufacturer_string", &self.manufacturer_string)
+            .field("product_string", &self.product_string)
+            .field("usage_page", &format_args!("{:#06x}", self.usage_page))
+            .field("usage", &format_args!("{:#06x}", self.usage))
+            .field("interface_number", &self.interface_number)
+            .field("bus_type", &self.bus_type)
             .finish()
     }
 }
 
+impl fmt::Display for DeviceInfo {
+    /// `vid:pid "product" if<interface> bus path`, leaving out what is unknown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vendor_id, self.product_id)?;
+        if let Some(product) = self.product_string() {
+            write!(f, " {:?}", product)?;
+        }
+        if self.interface_number >= 0 {
+            write!(f, " if{}", self.interface_number)?;
+        }
+        if self.bus_type != BusType::Unknown {
+            write!(f, " {}", self.bus_type)?;
+        }
+        if !self.path.as_bytes().is_empty() {
+            write!(f, " {}", self.path.to_string_lossy())?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of a read with a timeout, see [`HidDevice::read_with_timeout()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadOutcome {
+    /// A report of the given length was read.
+    Report(usize),
+    /// No report arrived in time.
+    TimedOut,
+}
+
+impl ReadOutcome {
+    /// Interpret the length returned by a backend, which returns 0 on timeout.
+    fn from_len(len: usize) -> Self {
+        match len {
+            0 => ReadOutcome::TimedOut,
+            len => ReadOutcome::Report(len),
+        }
+    }
+}
+
+/// Convert a timeout into milliseconds for [`HidDeviceBackend::read_timeout()`],
+/// rounding up and clamping it to `i32::MAX`.
+fn timeout_millis(timeout: Option<Duration>) -> i32 {
+    match timeout {
+        None => -1,
+        Some(timeout) => {
+            let millis = timeout.as_nanos().div_ceil(1_000_000);
+            millis.min(i32::MAX as u128) as i32
+        }
+    }
+}
+
+/// Call `read` with the time remaining until `deadline`, until it returns a
+/// report or the deadline has passed.
+fn read_until<F>(deadline: Instant, mut read: F) -> HidResult<ReadOutcome>
+where
+    F: FnMut(Option<Duration>) -> HidResult<ReadOutcome>,
+{
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match read(Some(remaining))? {
+            // Backends may return early, so keep waiting until the deadline
+            ReadOutcome::TimedOut if Instant::now() < deadline => continue,
+            outcome => return Ok(outcome),
+        }
+    }
+}
+
+/// Maximum number of reports kept by [`PendingReports`].
+const PENDING_CAPACITY: usize = 64;
+
+/// Input reports, which were read while waiting for another report, see
+/// [`HidDevice::transact()`]. Reads return them before reading from the device.
+#[derive(Default)]
+struct PendingReports(Mutex<VecDeque<Vec<u8>>>);
+
+impl PendingReports {
+    /// Keep a report, dropping the oldest one if too many are kept.
+    fn push(&self, report: Vec<u8>) {
+        let mut reports = self.0.lock().unwrap();
+        if reports.len() >= PENDING_CAPACITY {
+            reports.pop_front();
+        }
+        reports.push_back(report);
+    }
+
+    /// Copy the oldest report into `buf`, truncating it like a read would.
+    fn take(&self, buf: &mut [u8]) -> Option<usize> {
+        let report = self.0.lock().unwrap().pop_front()?;
+        let len = report.len().min(buf.len());
+        buf[..len].copy_from_slice(&report[..len]);
+        Some(len)
+    }
+}
+
+fn get_input_report(
+    backend: &dyn HidDeviceBackend,
+    report_id: u8,
+    buf: &mut [u8],
+) -> HidResult<usize> {
+    match buf.first_mut() {
+        Some(first) => *first = report_id,
+        None => return Err(HidError::InvalidZeroSizeData),
+    }
+    backend.get_input_report(buf)
+}
+
+fn write_report(backend: &dyn HidDeviceBackend, data: &[u8]) -> HidResult<usize> {
+    if data.is_empty() {
+        return Err(HidError::InvalidZeroSizeData);
+    }
+    backend.write(data)
+}
+
+/// Implements [`io::Write::write()`] with `write`, which writes a report.
+///
+/// A report is written completely or not at all, so the length of `buf` is
+/// returned even if the backend padded the report.
+fn io_write<F>(buf: &[u8], write: F) -> io::Result<usize>
+where
+    F: FnOnce(&[u8]) -> HidResult<usize>,
+{
+    if buf.is_empty() {
+        return Ok(0);
+    }
+    match write(buf)? {
+        sent if sent < buf.len() => Err(HidError::IncompleteSendError {
+            sent,
+            all: buf.len(),
+        }
+        .into()),
+        _ => Ok(buf.len()),
+    }
+}
+
+fn send_feature_report(backend: &dyn HidDeviceBackend, data: &[u8]) -> HidResult<()> {
+    if data.is_empty() {
+        return Err(HidError::InvalidZeroSizeData);
+    }
+    let res = backend.send_feature_report(data)?;
+    if res != data.len() {
+        Err(HidError::IncompleteSendError {
+            sent: res,
+            all: data.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Object for accessing HID device
+///
+/// `HidDevice` and `&HidDevice` implement [`io::Read`] and [`io::Write`], with
+/// one report per call of `read()` or `write()`:
+///
+/// - `read()` reads one input report, as [`HidDevice::read()`]. The buffer
+///   should be large enough for the largest report, because the rest of a
+///   report, which does not fit, is lost. In non-blocking mode (see
+///   [`HidDevice::set_blocking_mode()`]) it fails with
+///   [`io::ErrorKind::WouldBlock`] if no report is available.
+/// - `write()` writes the whole buffer as one output report, as
+///   [`HidDevice::write()`], so the buffer has to start with the report ID.
+///   It returns the length of the buffer, or fails if only part of the report
+///   was sent. An empty buffer writes nothing. `flush()` does nothing.
+///
+/// The inherent methods [`HidDevice::read()`] and [`HidDevice::write()`] take
+/// precedence over the trait methods, so call those explicitly, e.g. as
+/// `Read::read(&mut device, buf)`.
+///
+/// ```
+/// use hidapi::mock::{MockBackend, MockDevice};
+/// use hidapi::HidApi;
+/// use std::io::{self, Read, Write};
+///
+/// let mock = MockDevice::new(0x1234, 0x5678);
+/// let backend = MockBackend::new();
+/// backend.add_device(&mock);
+/// let mut device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+///
+/// device.write_all(&[0x00, 0x01, 0x02]).unwrap();
+/// assert_eq!(mock.writes(), vec![vec![0x00, 0x01, 0x02]]);
+/// assert_eq!(Write::write(&mut device, &[]).unwrap(), 0);
+///
+/// mock.push_input_report(&[0x03, 0x04]);
+/// let mut buf = [0u8; 64];
+/// assert_eq!(Read::read(&mut device, &mut buf).unwrap(), 2);
+///
+/// device.set_blocking_mode(false).unwrap();
+/// let err = Read::read(&mut device, &mut buf).unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+/// ```
 pub struct HidDevice {
-    _hid_device: *mut ffi::HidDevice,
+    inner: Box<dyn HidDeviceBackend>,
+    /// Mirrors the mode set with `set_blocking_mode()`, for the `io::Read`
+    /// implementation.
+    blocking: AtomicBool,
+    pending: PendingReports,
 }
 
-unsafe impl Send for HidDevice {}
-
 impl Debug for HidDevice {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("HidDevice").finish()
     }
 }
 
-impl Drop for HidDevice {
-    fn drop(&mut self) {
-        unsafe { ffi::hid_close(self._hid_device) }
-    }
-}
-
 impl HidDevice {
-    /// Check size returned by other methods, if it's equal to -1 check for
-    /// error and return Error, otherwise return size as unsigned number
-    fn check_size(&self, res: i32) -> HidResult<usize> {
-        if res == -1 {
-            match self.check_error() {
-                Ok(err) => Err(err),
-                Err(e) => Err(e),
-            }
-        } else {
-            Ok(res as usize)
+    fn new(inner: Box<dyn HidDeviceBackend>) -> Self {
+        HidDevice {
+            inner,
+            blocking: AtomicBool::new(true),
+            pending: PendingReports::default(),
         }
     }
 
@@ -557,14 +795,7 @@ impl HidDevice {
     /// library failed. The contained [HidError](enum.HidError.html) is the cause, why no error could
     /// be fetched.
     pub fn check_error(&self) -> HidResult<HidError> {
-        Ok(HidError::HidApiError {
-            message: unsafe {
-                match wchar_to_string(ffi::hid_error(self._hid_device)) {
-                    WcharString::String(s) => s,
-                    _ => return Err(HidError::HidApiErrorEmpty),
-                }
-            },
-        })
+        self.inner.check_error()
     }
 
     /// The first byte of `data` must contain the Report ID. For
@@ -579,20 +810,20 @@ impl HidDevice {
     /// `write()` will send the data on the first OUT endpoint, if
     /// one exists. If it does not, it will send the data through
     /// the Control Endpoint (Endpoint 0).
+    ///
+    /// [`HidDevice::write_output()`] takes the report ID separately instead.
     pub fn write(&self, data: &[u8]) -> HidResult<usize> {
-        if data.is_empty() {
-            return Err(HidError::InvalidZeroSizeData);
-        }
-        let res = unsafe { ffi::hid_write(self._hid_device, data.as_ptr(), data.len() as size_t) };
-        self.check_size(res)
+        write_report(&*self.inner, data)
     }
 
     /// Input reports are returned to the host through the 'INTERRUPT IN'
     /// endpoint. The first byte will contain the Report number if the device
     /// uses numbered reports.
     pub fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
-        let res = unsafe { ffi::hid_read(self._hid_device, buf.as_mut_ptr(), buf.len() as size_t) };
-        self.check_size(res)
+        if let Some(len) = self.pending.take(buf) {
+            return Ok(len);
+        }
+        self.inner.read(buf)
     }
 
     /// Input reports are returned to the host through the 'INTERRUPT IN'
@@ -600,15 +831,56 @@ impl HidDevice {
     /// uses numbered reports. Timeout measured in milliseconds, set -1 for
     /// blocking wait.
     pub fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
-        let res = unsafe {
-            ffi::hid_read_timeout(
-                self._hid_device,
-                buf.as_mut_ptr(),
-                buf.len() as size_t,
-                timeout,
-            )
-        };
-        self.check_size(res)
+        if let Some(len) = self.pending.take(buf) {
+            return Ok(len);
+        }
+        self.inner.read_timeout(buf, timeout)
+    }
+
+    /// Read an input report, waiting at most `timeout`, or forever if it is
+    /// `None`.
+    ///
+    /// Unlike [`HidDevice::read_timeout()`], a timeout is reported as
+    /// [`ReadOutcome::TimedOut`]. The timeout is rounded up to whole
+    /// milliseconds and durations longer than `i32::MAX` milliseconds (about 24
+    /// days) are shortened to that. The backends signal a timeout by reading 0
+    /// bytes, so an empty report is reported as a timeout as well.
+    ///
+    /// ```
+    /// use hidapi::mock::{MockBackend, MockDevice};
+    /// use hidapi::{HidApi, ReadOutcome};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mock = MockDevice::new(0x1234, 0x5678);
+    /// let backend = MockBackend::new();
+    /// backend.add_device(&mock);
+    /// let device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+    ///
+    /// let mut buf = [0u8; 64];
+    /// let timeout = Some(Duration::from_millis(10));
+    /// assert_eq!(device.read_with_timeout(&mut buf, timeout).unwrap(), ReadOutcome::TimedOut);
+    ///
+    /// mock.push_input_report(&[0x01, 0x02]);
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    /// assert_eq!(device.read_until(&mut buf, deadline).unwrap(), ReadOutcome::Report(2));
+    /// ```
+    pub fn read_with_timeout(
+        &self,
+        buf: &mut [u8],
+        timeout: Option<Duration>,
+    ) -> HidResult<ReadOutcome> {
+        let len = self.read_timeout(buf, timeout_millis(timeout))?;
+        Ok(ReadOutcome::from_len(len))
+    }
+
+    /// Read an input report, waiting until `deadline` at most.
+    ///
+    /// Loops, which have to finish at a fixed point in time, can pass the same
+    /// deadline to every call, instead of recalculating the remaining timeout.
+    /// If the deadline has already passed, this only checks for a report,
+    /// which is already available.
+    pub fn read_until(&self, buf: &mut [u8], deadline: Instant) -> HidResult<ReadOutcome> {
+        read_until(deadline, |timeout| self.read_with_timeout(buf, timeout))
     }
 
     /// Send a Feature report to the device.
@@ -622,32 +894,30 @@ impl HidDevice {
     /// `send_feature_report()`: 'the Report ID' (or 0x0, for devices which
     /// do not use numbered reports), followed by the report data (16 bytes).
     /// In this example, the length passed in would be 17.
+    ///
+    /// [`HidDevice::send_feature()`] takes the report ID separately instead.
     pub fn send_feature_report(&self, data: &[u8]) -> HidResult<()> {
-        if data.is_empty() {
-            return Err(HidError::InvalidZeroSizeData);
-        }
-        let res = unsafe {
-            ffi::hid_send_feature_report(self._hid_device, data.as_ptr(), data.len() as size_t)
-        };
-        let res = self.check_size(res)?;
-        if res != data.len() {
-            Err(HidError::IncompleteSendError {
-                sent: res,
-                all: data.len(),
-            })
-        } else {
-            Ok(())
-        }
+        send_feature_report(&*self.inner, data)
     }
 
     /// Set the first byte of `buf` to the 'Report ID' of the report to be read.
     /// Upon return, the first byte will still contain the Report ID, and the
     /// report data will start in `buf[1]`.
+    ///
+    /// [`HidDevice::get_feature()`] takes the report ID separately instead.
     pub fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
-        let res = unsafe {
-            ffi::hid_get_feature_report(self._hid_device, buf.as_mut_ptr(), buf.len() as size_t)
-        };
-        self.check_size(res)
+        self.inner.get_feature_report(buf)
+    }
+
+    /// Request the input report `report_id` through the control endpoint, as
+    /// a Get_Report transfer, instead of waiting for the device to send it.
+    ///
+    /// Use 0x0 as `report_id` for devices, which do not use numbered reports.
+    /// Upon return, the first byte of `buf` contains the Report ID and the
+    /// report data starts in `buf[1]`, so `buf` must be one byte longer than
+    /// the report. Returns the number of bytes read, including the Report ID.
+    pub fn get_input_report(&self, report_id: u8, buf: &mut [u8]) -> HidResult<usize> {
+        get_input_report(&*self.inner, report_id, buf)
     }
 
     /// Set the device handle to be in blocking or in non-blocking mode. In
@@ -656,87 +926,93 @@ impl HidDevice {
     /// wait (block) until there is data to read before returning.
     /// Modes can be changed at any time.
     pub fn set_blocking_mode(&self, blocking: bool) -> HidResult<()> {
-        let res = unsafe {
-            ffi::hid_set_nonblocking(self._hid_device, if blocking { 0i32 } else { 1i32 })
-        };
-        if res == -1 {
-            Err(HidError::SetBlockingModeError {
-                mode: match blocking {
-                    true => "blocking",
-                    false => "not blocking",
-                },
-            })
-        } else {
-            Ok(())
-        }
+        self.inner.set_blocking_mode(blocking)?;
+        self.blocking.store(blocking, Ordering::Relaxed);
+        Ok(())
     }
 
     /// Get The Manufacturer String from a HID device.
     pub fn get_manufacturer_string(&self) -> HidResult<Option<String>> {
-        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
-        let res = unsafe {
-            ffi::hid_get_manufacturer_string(
-                self._hid_device,
-                buf.as_mut_ptr(),
-                STRING_BUF_LEN as size_t,
-            )
-        };
-        let res = self.check_size(res)?;
-        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+        self.inner.get_manufacturer_string()
     }
 
     /// Get The Manufacturer String from a HID device.
     pub fn get_product_string(&self) -> HidResult<Option<String>> {
-        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
-        let res = unsafe {
-            ffi::hid_get_product_string(
-                self._hid_device,
-                buf.as_mut_ptr(),
-                STRING_BUF_LEN as size_t,
-            )
-        };
-        let res = self.check_size(res)?;
-        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+        self.inner.get_product_string()
     }
 
     /// Get The Serial Number String from a HID device.
     pub fn get_serial_number_string(&self) -> HidResult<Option<String>> {
-        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
-        let res = unsafe {
-            ffi::hid_get_serial_number_string(
-                self._hid_device,
-                buf.as_mut_ptr(),
-                STRING_BUF_LEN as size_t,
-            )
-        };
-        let res = self.check_size(res)?;
-        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+        self.inner.get_serial_number_string()
     }
 
     /// Get a string from a HID device, based on its string index.
     pub fn get_indexed_string(&self, index: i32) -> HidResult<Option<String>> {
-        let mut buf = [0 as wchar_t; STRING_BUF_LEN];
-        let res = unsafe {
-            ffi::hid_get_indexed_string(
-                self._hid_device,
-                index as c_int,
-                buf.as_mut_ptr(),
-                STRING_BUF_LEN,
-            )
-        };
-        let res = self.check_size(res)?;
-        unsafe { Ok(wchar_to_string(buf[..res].as_ptr()).into()) }
+        self.inner.get_indexed_string(index)
     }
 
     /// Get [`DeviceInfo`] from a HID device.
     pub fn get_device_info(&self) -> HidResult<DeviceInfo> {
-        let raw_device = unsafe { ffi::hid_get_device_info(self._hid_device) };
-        if raw_device.is_null() {
-            match self.check_error() {
-                Ok(err) | Err(err) => return Err(err),
+        self.inner.get_device_info()
+    }
+
+    /// Get the raw report descriptor of the device.
+    ///
+    /// The returned buffer contains exactly the bytes of the descriptor, which
+    /// are at most [`MAX_REPORT_DESCRIPTOR_SIZE`].
+    pub fn get_report_descriptor(&self) -> HidResult<Vec<u8>> {
+        self.inner.get_report_descriptor()
+    }
+
+    /// Get the layout of all reports, as declared by the report descriptor.
+    ///
+    /// Use it to size the buffers passed to [`HidDevice::read()`] and
+    /// [`HidDevice::write()`], and to find out whether the device uses numbered
+    /// reports.
+    pub fn get_report_layout(&self) -> HidResult<descriptor::ReportLayout> {
+        descriptor::ReportLayout::parse(&self.get_report_descriptor()?)
+    }
+}
+
+impl io::Read for &HidDevice {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            match HidDevice::read(self, buf)? {
+                // Reading 0 bytes would signal the end of the stream
+                0 if self.blocking.load(Ordering::Relaxed) => continue,
+                0 => return Err(io::ErrorKind::WouldBlock.into()),
+                len => return Ok(len),
             }
         }
+    }
+}
+
+impl io::Read for HidDevice {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        io::Read::read(&mut &*self, buf)
+    }
+}
+
+impl io::Write for &HidDevice {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io_write(buf, |buf| HidDevice::write(self, buf))
+    }
 
-        unsafe { conv_hid_device_info(raw_device) }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl io::Write for HidDevice {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::Write::write(&mut &*self, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
     }
 }
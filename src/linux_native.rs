@@ -0,0 +1,649 @@
+//! Pure rust backend for linux, which talks directly to the hidraw driver.
+//!
+//! Devices are enumerated through `/sys/class/hidraw` and opened through
+//! their `/dev/hidraw*` device nodes, so neither the hidapi C library nor
+//! libudev are required.
+
+use libc::{c_int, c_ulong, c_void};
+use std::ffi::{CStr, CString, OsStr};
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{MetadataExt, OpenOptionsExt};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::linux_uevent::UeventMonitor;
+use crate::{
+    BusType, DeviceInfo, HidApiBackend, HidDeviceBackend, HidError, HidResult, HotplugMonitor,
+    SyncDeviceBackend, WcharString, MAX_REPORT_DESCRIPTOR_SIZE,
+};
+
+const SYSFS_HIDRAW_CLASS: &str = "/sys/class/hidraw";
+
+// Bus types from linux/input.h
+const BUS_USB: u32 = 0x03;
+const BUS_BLUETOOTH: u32 = 0x05;
+const BUS_I2C: u32 = 0x18;
+const BUS_SPI: u32 = 0x1C;
+
+/// Last error, which was not related to a specific device.
+static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+fn register_global_error<T>(res: HidResult<T>) -> HidResult<T> {
+    if let Err(ref e) = res {
+        *LAST_ERROR.lock().unwrap() = Some(e.to_string());
+    }
+    res
+}
+
+/// Backend using the linux hidraw driver directly.
+pub struct HidrawApi;
+
+impl HidrawApi {
+    pub fn new(_enumerate: bool) -> HidResult<Self> {
+        Ok(HidrawApi)
+    }
+}
+
+impl HidApiBackend for HidrawApi {
+    fn get_hid_device_info_vector(&self) -> HidResult<Vec<DeviceInfo>> {
+        register_global_error(enumerate(0, 0))
+    }
+
+    fn enumerate(&self, vendor_id: u16, product_id: u16) -> HidResult<Vec<DeviceInfo>> {
+        register_global_error(enumerate(vendor_id, product_id))
+    }
+
+    fn open(&self, vid: u16, pid: u16) -> HidResult<Box<dyn HidDeviceBackend>> {
+        register_global_error(open_first(vid, pid, None))
+    }
+
+    fn open_serial(&self, vid: u16, pid: u16, sn: &str) -> HidResult<Box<dyn HidDeviceBackend>> {
+        register_global_error(open_first(vid, pid, Some(sn)))
+    }
+
+    fn open_path(&self, device_path: &CStr) -> HidResult<Box<dyn HidDeviceBackend>> {
+        register_global_error(HidrawDevice::open(device_path))
+    }
+
+    fn check_error(&self) -> HidResult<HidError> {
+        match *LAST_ERROR.lock().unwrap() {
+            Some(ref message) => Ok(HidError::HidApiError {
+                message: message.clone(),
+            }),
+            None => Err(HidError::HidApiErrorEmpty),
+        }
+    }
+
+    fn hotplug_monitor(&self) -> HidResult<Box<dyn HotplugMonitor>> {
+        Ok(Box::new(UeventMonitor::new()?))
+    }
+}
+
+/// Enumerate the hidraw devices with matching vendor and product id, where 0
+/// matches any id.
+fn enumerate(vendor_id: u16, product_id: u16) -> HidResult<Vec<DeviceInfo>> {
+    let entries = match fs::read_dir(SYSFS_HIDRAW_CLASS) {
+        Ok(entries) => entries,
+        // The hidraw driver is not loaded, so there can not be any devices
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut nodes = Vec::new();
+    for entry in entries {
+        let name = entry?.file_name();
+        if let Some(num) = name
+            .to_str()
+            .and_then(|n| n.strip_prefix("hidraw"))
+            .and_then(|n| n.parse::<u32>().ok())
+        {
+            nodes.push(num);
+        }
+    }
+    nodes.sort_unstable();
+
+    let mut devices = Vec::with_capacity(nodes.len());
+    for num in nodes {
+        let sysfs = Path::new(SYSFS_HIDRAW_CLASS).join(format!("hidraw{}", num));
+        let devnode = CString::new(format!("/dev/hidraw{}", num)).unwrap();
+
+        // Devices might disappear while the list is walked, or use a bus
+        // which is not exposed by hidapi. Both are skipped silently.
+        if let Ok(Some(mut infos)) =
+            device_infos_from_sysfs(&sysfs, &devnode, vendor_id, product_id)
+        {
+            devices.append(&mut infos);
+        }
+    }
+
+    Ok(devices)
+}
+
+fn open_first(vid: u16, pid: u16, sn: Option<&str>) -> HidResult<Box<dyn HidDeviceBackend>> {
+    let device = enumerate(vid, pid)?
+        .into_iter()
+        .find(|dev| sn.is_none() || dev.serial_number() == sn);
+
+    match device {
+        Some(dev) => HidrawDevice::open(&dev.path),
+        None => Err(HidError::HidApiError {
+            message: "No HID devices with requested VID/PID/SerialNumber found in the system."
+                .into(),
+        }),
+    }
+}
+
+/// Read a sysfs attribute, stripping the trailing newline.
+fn read_attribute(dir: &Path, name: &str) -> Option<String> {
+    fs::read(dir.join(name)).ok().map(|bytes| {
+        String::from_utf8_lossy(&bytes)
+            .trim_end_matches('\n')
+            .to_owned()
+    })
+}
+
+fn read_hex_attribute(dir: &Path, name: &str) -> Option<u16> {
+    read_attribute(dir, name).and_then(|v| u16::from_str_radix(v.trim(), 16).ok())
+}
+
+/// The fields of the `uevent` file of a HID device, which are needed to
+/// build a [`DeviceInfo`].
+#[derive(Default)]
+struct HidUevent {
+    bus: u32,
+    vendor_id: u16,
+    product_id: u16,
+    name: String,
+    uniq: String,
+}
+
+fn parse_uevent(uevent: &str) -> Option<HidUevent> {
+    let mut info = HidUevent::default();
+    let mut have_id = false;
+
+    for line in uevent.lines() {
+        let (key, value) = match line.split_once('=') {
+            Some(kv) => kv,
+            None => continue,
+        };
+        match key {
+            "HID_ID" => {
+                // Format: BBBB:VVVVVVVV:PPPPPPPP
+                let mut parts = value.split(':');
+                let mut next = || parts.next().and_then(|p| u32::from_str_radix(p, 16).ok());
+                info.bus = next()?;
+                info.vendor_id = next()? as u16;
+                info.product_id = next()? as u16;
+                have_id = true;
+            }
+            "HID_NAME" => info.name = value.to_owned(),
+            "HID_UNIQ" => info.uniq = value.to_owned(),
+            _ => {}
+        }
+    }
+
+    if have_id {
+        Some(info)
+    } else {
+        None
+    }
+}
+
+/// Build the device infos of a hidraw node, one per top level collection.
+///
+/// `sysfs` is the directory of the hidraw class device, e.g.
+/// `/sys/class/hidraw/hidraw0`. Returns `None` if the device is on a bus
+/// hidapi does not report, or if its vendor or product id does not match.
+/// An id of 0 matches any device.
+fn device_infos_from_sysfs(
+    sysfs: &Path,
+    devnode: &CStr,
+    vendor_id: u16,
+    product_id: u16,
+) -> io::Result<Option<Vec<DeviceInfo>>> {
+    let hid_dev = fs::canonicalize(sysfs.join("device"))?;
+    let uevent = fs::read_to_string(hid_dev.join("uevent"))?;
+    let uevent = match parse_uevent(&uevent) {
+        Some(uevent) => uevent,
+        None => return Ok(None),
+    };
+    if (vendor_id != 0 && uevent.vendor_id != vendor_id)
+        || (product_id != 0 && uevent.product_id != product_id)
+    {
+        return Ok(None);
+    }
+
+    let bus_type = match uevent.bus {
+        BUS_USB => BusType::Usb,
+        BUS_BLUETOOTH => BusType::Bluetooth,
+        BUS_I2C => BusType::I2c,
+        BUS_SPI => BusType::Spi,
+        _ => return Ok(None),
+    };
+
+    let mut info = DeviceInfo {
+        path: devnode.to_owned(),
+        vendor_id: uevent.vendor_id,
+        product_id: uevent.product_id,
+        serial_number: WcharString::String(uevent.uniq),
+        release_number: 0,
+        manufacturer_string: WcharString::String(String::new()),
+        product_string: WcharString::String(uevent.name),
+        usage_page: 0,
+        usage: 0,
+        interface_number: -1,
+        bus_type,
+    };
+
+    if let BusType::Usb = bus_type {
+        // The USB interface is the parent of the HID device, and the USB
+        // device is the parent of the interface.
+        if let Some(intf) = find_ancestor_with(&hid_dev, "bInterfaceNumber") {
+            if let Some(num) = read_hex_attribute(&intf, "bInterfaceNumber") {
+                info.interface_number = num as i32;
+            }
+        }
+        if let Some(usb_dev) = find_ancestor_with(&hid_dev, "idVendor") {
+            if let Some(release) = read_hex_attribute(&usb_dev, "bcdDevice") {
+                info.release_number = release;
+            }
+            if let Some(s) = read_attribute(&usb_dev, "manufacturer") {
+                info.manufacturer_string = WcharString::String(s);
+            }
+            if let Some(s) = read_attribute(&usb_dev, "product") {
+                info.product_string = WcharString::String(s);
+            }
+            if let Some(s) = read_attribute(&usb_dev, "serial") {
+                info.serial_number = WcharString::String(s);
+            }
+        }
+    }
+
+    let descriptor = fs::read(hid_dev.join("report_descriptor")).unwrap_or_default();
+    let usages = top_level_usages(&descriptor);
+    if usages.is_empty() {
+        return Ok(Some(vec![info]));
+    }
+
+    Ok(Some(
+        usages
+            .into_iter()
+            .map(|(usage_page, usage)| DeviceInfo {
+                usage_page,
+                usage,
+                ..info.clone()
+            })
+            .collect(),
+    ))
+}
+
+fn find_ancestor_with(dir: &Path, attribute: &str) -> Option<PathBuf> {
+    dir.ancestors()
+        .skip(1)
+        .take_while(|p| *p != Path::new("/sys/devices"))
+        .find(|p| p.join(attribute).exists())
+        .map(Path::to_path_buf)
+}
+
+/// Scan a report descriptor for the usage pages and usages of its top level
+/// collections.
+fn top_level_usages(descriptor: &[u8]) -> Vec<(u16, u16)> {
+    let mut usages = Vec::new();
+    let mut usage_page = 0u16;
+    let mut usage = None;
+    let mut depth = 0usize;
+    let mut pos = 0;
+
+    while pos < descriptor.len() {
+        let prefix = descriptor[pos];
+
+        // Long items: the data size is in the next byte, skip them
+        if prefix == 0xFE {
+            let size = descriptor.get(pos + 1).copied().unwrap_or(0) as usize;
+            pos += 3 + size;
+            continue;
+        }
+
+        let size = match prefix & 0x03 {
+            3 => 4,
+            s => s as usize,
+        };
+        let data = match descriptor.get(pos + 1..pos + 1 + size) {
+            Some(data) => data,
+            None => break,
+        };
+        let value = data
+            .iter()
+            .rev()
+            .fold(0u32, |acc, &b| (acc << 8) | b as u32);
+
+        match prefix & 0xFC {
+            // Usage Page
+            0x04 => usage_page = value as u16,
+            // Usage, optionally with the usage page in the upper 16 bits
+            0x08 if usage.is_none() => {
+                usage = Some(if size == 4 {
+                    ((value >> 16) as u16, value as u16)
+                } else {
+                    (usage_page, value as u16)
+                });
+            }
+            // Collection
+            0xA0 => {
+                if depth == 0 {
+                    if let Some((page, usage)) = usage {
+                        let page = if page == 0 { usage_page } else { page };
+                        usages.push((page, usage));
+                    }
+                }
+                depth += 1;
+                usage = None;
+            }
+            // End Collection
+            0xC0 => {
+                depth = depth.saturating_sub(1);
+                usage = None;
+            }
+            // Remaining main items clear the local state
+            0x80 | 0x90 | 0xB0 => usage = None,
+            _ => {}
+        }
+
+        pos += 1 + size;
+    }
+
+    usages
+}
+
+// Ioctl request encoding, see asm-generic/ioctl.h
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "mips32r6",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+mod ioc {
+    pub const SIZEBITS: u32 = 13;
+    pub const WRITE: u32 = 4;
+    pub const READ: u32 = 2;
+}
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "mips32r6",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+mod ioc {
+    pub const SIZEBITS: u32 = 14;
+    pub const WRITE: u32 = 1;
+    pub const READ: u32 = 2;
+}
+
+const fn ioc(dir: u32, nr: u32, size: usize) -> c_ulong {
+    const NRSHIFT: u32 = 0;
+    const TYPESHIFT: u32 = 8;
+    const SIZESHIFT: u32 = 16;
+    const DIRSHIFT: u32 = SIZESHIFT + ioc::SIZEBITS;
+    const HIDRAW_TYPE: u32 = b'H' as u32;
+
+    ((dir << DIRSHIFT)
+        | (HIDRAW_TYPE << TYPESHIFT)
+        | (nr << NRSHIFT)
+        | ((size as u32) << SIZESHIFT)) as c_ulong
+}
+
+/// `struct hidraw_report_descriptor` from linux/hidraw.h
+#[repr(C)]
+struct HidrawReportDescriptor {
+    size: u32,
+    value: [u8; MAX_REPORT_DESCRIPTOR_SIZE],
+}
+
+/// `HIDIOCGRDESCSIZE` from linux/hidraw.h
+const HIDIOCGRDESCSIZE: c_ulong = ioc(ioc::READ, 0x01, std::mem::size_of::<c_int>());
+
+/// `HIDIOCGRDESC` from linux/hidraw.h
+const HIDIOCGRDESC: c_ulong = ioc(
+    ioc::READ,
+    0x02,
+    std::mem::size_of::<HidrawReportDescriptor>(),
+);
+
+/// `HIDIOCSFEATURE(len)` from linux/hidraw.h
+const fn hidioc_sfeature(len: usize) -> c_ulong {
+    ioc(ioc::WRITE | ioc::READ, 0x06, len)
+}
+
+/// `HIDIOCGFEATURE(len)` from linux/hidraw.h
+const fn hidioc_gfeature(len: usize) -> c_ulong {
+    ioc(ioc::WRITE | ioc::READ, 0x07, len)
+}
+
+/// `HIDIOCGINPUT(len)` from linux/hidraw.h
+const fn hidioc_ginput(len: usize) -> c_ulong {
+    ioc(ioc::WRITE | ioc::READ, 0x0A, len)
+}
+
+/// Handle to a `/dev/hidraw*` device node.
+pub struct HidrawDevice {
+    file: File,
+    blocking: AtomicBool,
+    last_error: Mutex<Option<String>>,
+}
+
+impl HidrawDevice {
+    fn open(path: &CStr) -> HidResult<Box<dyn HidDeviceBackend>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_CLOEXEC)
+            .open(OsStr::from_bytes(path.to_bytes()))?;
+
+        Ok(Box::new(HidrawDevice {
+            file,
+            blocking: AtomicBool::new(true),
+            last_error: Mutex::new(None),
+        }))
+    }
+
+    /// Remember the error of a failed operation for [`Self::check_error`].
+    fn register_error<T>(&self, res: HidResult<T>) -> HidResult<T> {
+        if let Err(ref e) = res {
+            *self.last_error.lock().unwrap() = Some(e.to_string());
+        }
+        res
+    }
+
+    /// Convert the return value of a libc call, which signals errors with -1.
+    fn check_ret(&self, res: isize) -> HidResult<usize> {
+        if res < 0 {
+            self.register_error(Err(io::Error::last_os_error().into()))
+        } else {
+            Ok(res as usize)
+        }
+    }
+
+    fn ioctl(&self, request: c_ulong, arg: *mut c_void) -> HidResult<usize> {
+        let res = unsafe { libc::ioctl(self.file.as_raw_fd(), request as _, arg) };
+        self.check_ret(res as isize)
+    }
+
+    /// The sysfs directory of the hidraw class device behind this handle.
+    fn sysfs_path(&self) -> HidResult<PathBuf> {
+        let rdev = self.file.metadata()?.rdev();
+        let major = ((rdev >> 32) & 0xffff_f000) | ((rdev >> 8) & 0x0fff);
+        let minor = ((rdev >> 12) & 0xffff_ff00) | (rdev & 0x00ff);
+        Ok(PathBuf::from(format!("/sys/dev/char/{}:{}", major, minor)))
+    }
+}
+
+impl HidDeviceBackend for HidrawDevice {
+    fn check_error(&self) -> HidResult<HidError> {
+        match *self.last_error.lock().unwrap() {
+            Some(ref message) => Ok(HidError::HidApiError {
+                message: message.clone(),
+            }),
+            None => Err(HidError::HidApiErrorEmpty),
+        }
+    }
+
+    fn write(&self, data: &[u8]) -> HidResult<usize> {
+        let res = unsafe {
+            libc::write(
+                self.file.as_raw_fd(),
+                data.as_ptr() as *const c_void,
+                data.len(),
+            )
+        };
+        self.check_ret(res)
+    }
+
+    fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let timeout = if self.blocking.load(Ordering::Relaxed) {
+            -1
+        } else {
+            0
+        };
+        self.read_timeout(buf, timeout)
+    }
+
+    fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
+        let mut pfd = libc::pollfd {
+            fd: self.file.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+
+        let res = loop {
+            let res = unsafe { libc::poll(&mut pfd, 1, timeout as c_int) };
+            if res == -1 && io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            break res;
+        };
+
+        match res {
+            -1 => return self.check_ret(-1),
+            // Timeout
+            0 => return Ok(0),
+            _ => {}
+        }
+
+        if pfd.revents & (libc::POLLERR | libc::POLLHUP | libc::POLLNVAL) != 0 {
+            return self.register_error(Err(HidError::HidApiError {
+                message: "hid_read_timeout: unexpected poll error (device disconnected)".into(),
+            }));
+        }
+
+        let res = unsafe {
+            libc::read(
+                self.file.as_raw_fd(),
+                buf.as_mut_ptr() as *mut c_void,
+                buf.len(),
+            )
+        };
+        if res < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::WouldBlock {
+                return Ok(0);
+            }
+            return self.register_error(Err(err.into()));
+        }
+        Ok(res as usize)
+    }
+
+    fn send_feature_report(&self, data: &[u8]) -> HidResult<usize> {
+        // The ioctl takes a mutable pointer, but only reads from it
+        let mut buf = data.to_vec();
+        self.ioctl(hidioc_sfeature(buf.len()), buf.as_mut_ptr() as *mut c_void)
+    }
+
+    fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.ioctl(hidioc_gfeature(buf.len()), buf.as_mut_ptr() as *mut c_void)
+    }
+
+    fn get_input_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.ioctl(hidioc_ginput(buf.len()), buf.as_mut_ptr() as *mut c_void)
+    }
+
+    fn set_blocking_mode(&self, blocking: bool) -> HidResult<()> {
+        // Reads are always done through poll(), so the file descriptor
+        // itself can stay in blocking mode.
+        self.blocking.store(blocking, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn get_manufacturer_string(&self) -> HidResult<Option<String>> {
+        let info = self.get_device_info()?;
+        Ok(info.manufacturer_string.into())
+    }
+
+    fn get_product_string(&self) -> HidResult<Option<String>> {
+        let info = self.get_device_info()?;
+        Ok(info.product_string.into())
+    }
+
+    fn get_serial_number_string(&self) -> HidResult<Option<String>> {
+        let info = self.get_device_info()?;
+        Ok(info.serial_number.into())
+    }
+
+    fn get_indexed_string(&self, _index: i32) -> HidResult<Option<String>> {
+        self.register_error(Err(HidError::HidApiError {
+            message: "hid_get_indexed_string: not supported by hidraw".into(),
+        }))
+    }
+
+    fn get_device_info(&self) -> HidResult<DeviceInfo> {
+        let res = self.sysfs_path().and_then(|sysfs| {
+            let name = fs::canonicalize(&sysfs)?;
+            let name = name.file_name().unwrap_or_default().as_bytes();
+            let devnode = CString::new([b"/dev/".as_ref(), name].concat()).unwrap();
+
+            match device_infos_from_sysfs(&sysfs, &devnode, 0, 0)? {
+                Some(infos) => Ok(infos.into_iter().next().unwrap()),
+                None => Err(HidError::HidApiError {
+                    message: "hid_get_device_info: unsupported bus type".into(),
+                }),
+            }
+        });
+        self.register_error(res)
+    }
+
+    fn get_report_descriptor(&self) -> HidResult<Vec<u8>> {
+        let mut size: c_int = 0;
+        self.ioctl(HIDIOCGRDESCSIZE, &mut size as *mut c_int as *mut c_void)?;
+
+        let mut descriptor = Box::new(HidrawReportDescriptor {
+            size: size as u32,
+            value: [0; MAX_REPORT_DESCRIPTOR_SIZE],
+        });
+        self.ioctl(
+            HIDIOCGRDESC,
+            &mut *descriptor as *mut HidrawReportDescriptor as *mut c_void,
+        )?;
+
+        let len = (descriptor.size as usize).min(MAX_REPORT_DESCRIPTOR_SIZE);
+        Ok(descriptor.value[..len].to_vec())
+    }
+
+    fn into_sync(self: Box<Self>) -> Result<SyncDeviceBackend, Box<dyn HidDeviceBackend>> {
+        Ok(self)
+    }
+
+    fn pollable_fd(&self) -> Option<RawFd> {
+        Some(self.file.as_raw_fd())
+    }
+}
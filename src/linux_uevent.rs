@@ -0,0 +1,121 @@
+//! Hotplug monitor for the hidraw backends, which listens to the device events
+//! broadcast by udev.
+//!
+//! This is the netlink socket a libudev monitor reads from, so libudev is not
+//! needed. The events are only scanned for the hidraw subsystem, the devices
+//! themselves are enumerated again afterwards.
+
+use libc::{c_int, c_void};
+use std::io;
+use std::mem;
+use std::os::unix::io::{AsRawFd, FromRawFd, OwnedFd};
+
+use crate::{HidResult, HotplugMonitor};
+
+/// Multicast group of the events sent by udev, after it has created the device
+/// node and applied its rules. The kernel sends its raw events to group 1.
+const UDEV_MONITOR_GROUP: u32 = 2;
+
+const HIDRAW_SUBSYSTEM: &[u8] = b"SUBSYSTEM=hidraw\0";
+
+/// Large enough for any uevent, which the kernel limits to 2048 bytes of
+/// properties, plus the header added by udev.
+const EVENT_BUF_LEN: usize = 8192;
+
+pub struct UeventMonitor {
+    socket: OwnedFd,
+    buf: Vec<u8>,
+}
+
+impl UeventMonitor {
+    pub fn new() -> HidResult<Self> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_DGRAM | libc::SOCK_CLOEXEC | libc::SOCK_NONBLOCK,
+                libc::NETLINK_KOBJECT_UEVENT,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        let mut addr: libc::sockaddr_nl = unsafe { mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        addr.nl_groups = UDEV_MONITOR_GROUP;
+        let res = unsafe {
+            libc::bind(
+                socket.as_raw_fd(),
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        Ok(UeventMonitor {
+            socket,
+            buf: vec![0u8; EVENT_BUF_LEN],
+        })
+    }
+
+    /// Read all pending events, returning whether one of them was about a
+    /// hidraw device.
+    fn drain(&mut self) -> io::Result<bool> {
+        let mut changed = false;
+        loop {
+            let res = unsafe {
+                libc::recv(
+                    self.socket.as_raw_fd(),
+                    self.buf.as_mut_ptr() as *mut c_void,
+                    self.buf.len(),
+                    libc::MSG_DONTWAIT,
+                )
+            };
+            if res < 0 {
+                let err = io::Error::last_os_error();
+                return match err.kind() {
+                    io::ErrorKind::WouldBlock => Ok(changed),
+                    io::ErrorKind::Interrupted => continue,
+                    // Events were dropped, so assume one of them was relevant
+                    _ if err.raw_os_error() == Some(libc::ENOBUFS) => {
+                        changed = true;
+                        continue;
+                    }
+                    _ => Err(err),
+                };
+            }
+
+            let event = &self.buf[..res as usize];
+            changed |= event
+                .windows(HIDRAW_SUBSYSTEM.len())
+                .any(|w| w == HIDRAW_SUBSYSTEM);
+        }
+    }
+}
+
+impl HotplugMonitor for UeventMonitor {
+    fn wait(&mut self, timeout: i32) -> HidResult<bool> {
+        let mut pfd = libc::pollfd {
+            fd: self.socket.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+
+        let res = unsafe { libc::poll(&mut pfd, 1, timeout as c_int) };
+        if res < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::Interrupted {
+                return Ok(false);
+            }
+            return Err(err.into());
+        }
+        if res == 0 {
+            return Ok(false);
+        }
+
+        Ok(self.drain()?)
+    }
+}
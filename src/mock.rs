@@ -0,0 +1,591 @@
+//! In-memory backend, which allows testing code that talks to HID devices
+//! without any hardware attached.
+//!
+//! Virtual devices are described with [`MockDevice`] and registered with a
+//! [`MockBackend`], which is then passed to [`HidApi::with_backend()`]. The
+//! [`MockDevice`] handle stays connected to the backend, so tests can queue
+//! input reports and inspect what was sent to the device while the code under
+//! test uses the regular [`HidApi`] and [`HidDevice`](crate::HidDevice) API.
+//!
+//! ```
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::HidApi;
+//!
+//! let device = MockDevice::new(0x1234, 0x5678).with_product_string("Widget");
+//! // Answer the "get version" command with a version report
+//! device.expect_write_with_reply(&[0x00, 0x01], &[0x01, 0x02, 0x03]);
+//!
+//! let backend = MockBackend::new();
+//! backend.add_device(&device);
+//!
+//! let api = HidApi::with_backend(backend).unwrap();
+//! let dev = api.open(0x1234, 0x5678).unwrap();
+//! assert_eq!(dev.get_product_string().unwrap().as_deref(), Some("Widget"));
+//!
+//! dev.write(&[0x00, 0x01]).unwrap();
+//! let mut buf = [0u8; 64];
+//! let len = dev.read_timeout(&mut buf, 100).unwrap();
+//! assert_eq!(&buf[..len], &[0x01, 0x02, 0x03]);
+//!
+//! assert_eq!(device.pending_expectations(), 0);
+//! assert_eq!(device.writes(), vec![vec![0x00, 0x01]]);
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::{CStr, CString};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::hotplug::PollingMonitor;
+use crate::{
+    BusType, DeviceInfo, HidApiBackend, HidDeviceBackend, HidError, HidResult, HotplugMonitor,
+    SyncDeviceBackend, WcharString,
+};
+
+#[cfg(doc)]
+use crate::HidApi;
+
+/// Used to give every mock device a unique default path.
+static NEXT_DEVICE: AtomicUsize = AtomicUsize::new(0);
+
+fn mock_error(message: String) -> HidError {
+    HidError::HidApiError { message }
+}
+
+/// Backend, which serves the [`MockDevice`]s registered with it.
+///
+/// Cloning a `MockBackend` returns another handle to the same set of devices.
+#[derive(Clone, Default)]
+pub struct MockBackend {
+    devices: Arc<Mutex<Vec<MockDevice>>>,
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl MockBackend {
+    /// Create a backend without any devices.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a device, so it is enumerated and can be opened.
+    pub fn add_device(&self, device: &MockDevice) {
+        self.devices.lock().unwrap().push(device.clone());
+    }
+
+    /// Unregister a device. Handles, which are already open, stay usable.
+    pub fn remove_device(&self, device: &MockDevice) {
+        self.devices
+            .lock()
+            .unwrap()
+            .retain(|d| !Arc::ptr_eq(&d.shared, &device.shared));
+    }
+
+    /// Returns all registered devices.
+    pub fn devices(&self) -> Vec<MockDevice> {
+        self.devices.lock().unwrap().clone()
+    }
+
+    fn open_first<F>(&self, matches: F) -> HidResult<Box<dyn HidDeviceBackend>>
+    where
+        F: Fn(&DeviceInfo) -> bool,
+    {
+        let device = self
+            .devices
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|d| {
+                let state = d.state();
+                state.connected && matches(&state.info)
+            })
+            .cloned();
+
+        match device {
+            Some(device) => Ok(Box::new(MockHandle::new(device))),
+            None => {
+                let message = "mock: no matching device found".to_owned();
+                *self.last_error.lock().unwrap() = Some(message.clone());
+                Err(mock_error(message))
+            }
+        }
+    }
+}
+
+impl HidApiBackend for MockBackend {
+    fn get_hid_device_info_vector(&self) -> HidResult<Vec<DeviceInfo>> {
+        Ok(self
+            .devices
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|d| d.state())
+            .filter(|state| state.connected)
+            .map(|state| state.info.clone())
+            .collect())
+    }
+
+    fn open(&self, vid: u16, pid: u16) -> HidResult<Box<dyn HidDeviceBackend>> {
+        self.open_first(|info| info.vendor_id == vid && info.product_id == pid)
+    }
+
+    fn open_serial(&self, vid: u16, pid: u16, sn: &str) -> HidResult<Box<dyn HidDeviceBackend>> {
+        self.open_first(|info| {
+            info.vendor_id == vid && info.product_id == pid && info.serial_number() == Some(sn)
+        })
+    }
+
+    fn open_path(&self, device_path: &CStr) -> HidResult<Box<dyn HidDeviceBackend>> {
+        self.open_first(|info| info.path.as_c_str() == device_path)
+    }
+
+    fn check_error(&self) -> HidResult<HidError> {
+        match *self.last_error.lock().unwrap() {
+            Some(ref message) => Ok(mock_error(message.clone())),
+            None => Err(HidError::HidApiErrorEmpty),
+        }
+    }
+
+    /// Devices are re-enumerated every 10 ms, so changes made with
+    /// [`MockBackend::add_device()`], [`MockDevice::disconnect()`] and the like
+    /// are reported quickly.
+    fn hotplug_monitor(&self) -> HidResult<Box<dyn HotplugMonitor>> {
+        Ok(Box::new(PollingMonitor::new(Duration::from_millis(10))))
+    }
+}
+
+struct MockState {
+    info: DeviceInfo,
+    connected: bool,
+    /// Incremented on every disconnect, so handles opened before can be
+    /// told apart from handles opened after a reconnect.
+    generation: u64,
+    input_reports: VecDeque<Vec<u8>>,
+    expected_writes: VecDeque<(Vec<u8>, Option<Vec<u8>>)>,
+    writes: Vec<Vec<u8>>,
+    feature_reports: HashMap<u8, Vec<u8>>,
+    /// Reports returned by `get_input_report()`, independent of the queued
+    /// `input_reports`.
+    requested_input_reports: HashMap<u8, Vec<u8>>,
+    sent_feature_reports: Vec<Vec<u8>>,
+    indexed_strings: HashMap<i32, String>,
+    report_descriptor: Option<Vec<u8>>,
+}
+
+struct MockShared {
+    state: Mutex<MockState>,
+    input_available: Condvar,
+}
+
+/// A virtual device.
+///
+/// The builder style `with_*` methods describe the [`DeviceInfo`] the device
+/// is enumerated with, the remaining methods script its behaviour and record
+/// the traffic it received. Cloning a `MockDevice` returns another handle to
+/// the same device.
+///
+/// All handles opened for a device share one queue of input reports, each
+/// queued report is returned by exactly one read.
+#[derive(Clone)]
+pub struct MockDevice {
+    shared: Arc<MockShared>,
+}
+
+impl MockDevice {
+    /// Create a USB device with the given vendor and product id and a unique path.
+    pub fn new(vendor_id: u16, product_id: u16) -> Self {
+        let index = NEXT_DEVICE.fetch_add(1, Ordering::Relaxed);
+        let info = DeviceInfo {
+            path: CString::new(format!("mock:{}", index)).unwrap(),
+            vendor_id,
+            product_id,
+            serial_number: WcharString::None,
+            release_number: 0,
+            manufacturer_string: WcharString::None,
+            product_string: WcharString::None,
+            usage_page: 0,
+            usage: 0,
+            interface_number: -1,
+            bus_type: BusType::Usb,
+        };
+
+        MockDevice {
+            shared: Arc::new(MockShared {
+                state: Mutex::new(MockState {
+                    info,
+                    connected: true,
+                    generation: 0,
+                    input_reports: VecDeque::new(),
+                    expected_writes: VecDeque::new(),
+                    writes: Vec::new(),
+                    feature_reports: HashMap::new(),
+                    requested_input_reports: HashMap::new(),
+                    sent_feature_reports: Vec::new(),
+                    indexed_strings: HashMap::new(),
+                    report_descriptor: None,
+                }),
+                input_available: Condvar::new(),
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.shared.state.lock().unwrap()
+    }
+
+    fn update_info<F: FnOnce(&mut DeviceInfo)>(self, f: F) -> Self {
+        f(&mut self.state().info);
+        self
+    }
+
+    /// Set the path used to open the device with [`HidApi::open_path()`].
+    pub fn with_path(self, path: &str) -> Self {
+        let path = CString::new(path).expect("path must not contain nul bytes");
+        self.update_info(|info| info.path = path)
+    }
+
+    pub fn with_serial_number(self, serial_number: &str) -> Self {
+        self.update_info(|info| info.serial_number = WcharString::String(serial_number.into()))
+    }
+
+    pub fn with_release_number(self, release_number: u16) -> Self {
+        self.update_info(|info| info.release_number = release_number)
+    }
+
+    pub fn with_manufacturer_string(self, manufacturer: &str) -> Self {
+        self.update_info(|info| info.manufacturer_string = WcharString::String(manufacturer.into()))
+    }
+
+    pub fn with_product_string(self, product: &str) -> Self {
+        self.update_info(|info| info.product_string = WcharString::String(product.into()))
+    }
+
+    pub fn with_usage(self, usage_page: u16, usage: u16) -> Self {
+        self.update_info(|info| {
+            info.usage_page = usage_page;
+            info.usage = usage;
+        })
+    }
+
+    pub fn with_interface_number(self, interface_number: i32) -> Self {
+        self.update_info(|info| info.interface_number = interface_number)
+    }
+
+    pub fn with_bus_type(self, bus_type: BusType) -> Self {
+        self.update_info(|info| info.bus_type = bus_type)
+    }
+
+    /// Set the string returned by `get_indexed_string(index)`.
+    pub fn with_indexed_string(self, index: i32, string: &str) -> Self {
+        self.state().indexed_strings.insert(index, string.into());
+        self
+    }
+
+    /// Set the report descriptor returned by `get_report_descriptor()`.
+    pub fn with_report_descriptor(self, descriptor: &[u8]) -> Self {
+        self.state().report_descriptor = Some(descriptor.to_vec());
+        self
+    }
+
+    /// The information this device is enumerated with.
+    pub fn info(&self) -> DeviceInfo {
+        self.state().info.clone()
+    }
+
+    /// Queue an input report, which will be returned by the next read.
+    ///
+    /// As with real devices, the report starts with the report ID if the
+    /// device uses numbered reports.
+    pub fn push_input_report(&self, report: &[u8]) {
+        self.state().input_reports.push_back(report.to_vec());
+        self.shared.input_available.notify_all();
+    }
+
+    /// Number of queued input reports, which were not read yet.
+    pub fn pending_input_reports(&self) -> usize {
+        self.state().input_reports.len()
+    }
+
+    /// Expect `data` as the next output report.
+    ///
+    /// Expectations are checked in the order they were added. While there are
+    /// pending expectations, a write with different data fails with an error.
+    /// Without pending expectations every write succeeds.
+    pub fn expect_write(&self, data: &[u8]) {
+        self.state()
+            .expected_writes
+            .push_back((data.to_vec(), None));
+    }
+
+    /// Like [`MockDevice::expect_write()`], but queues `reply` as input report
+    /// when the expected write happened.
+    pub fn expect_write_with_reply(&self, data: &[u8], reply: &[u8]) {
+        self.state()
+            .expected_writes
+            .push_back((data.to_vec(), Some(reply.to_vec())));
+    }
+
+    /// Number of expected writes, which did not happen yet.
+    pub fn pending_expectations(&self) -> usize {
+        self.state().expected_writes.len()
+    }
+
+    /// All output reports successfully written to the device, in order.
+    pub fn writes(&self) -> Vec<Vec<u8>> {
+        self.state().writes.clone()
+    }
+
+    /// Set the feature report returned for the report ID in `report[0]`.
+    pub fn set_feature_report(&self, report: &[u8]) {
+        assert!(
+            !report.is_empty(),
+            "feature report must contain the report ID"
+        );
+        self.state()
+            .feature_reports
+            .insert(report[0], report.to_vec());
+    }
+
+    /// Set the input report returned by `get_input_report()` for the report ID
+    /// in `report[0]`.
+    ///
+    /// Unlike reports queued with [`MockDevice::push_input_report()`], it is
+    /// not consumed and not returned by reads.
+    pub fn set_input_report(&self, report: &[u8]) {
+        assert!(
+            !report.is_empty(),
+            "input report must contain the report ID"
+        );
+        self.state()
+            .requested_input_reports
+            .insert(report[0], report.to_vec());
+    }
+
+    /// All feature reports sent to the device, in order.
+    pub fn sent_feature_reports(&self) -> Vec<Vec<u8>> {
+        self.state().sent_feature_reports.clone()
+    }
+
+    /// Simulate unplugging the device.
+    ///
+    /// The device is no longer enumerated and all I/O on open handles fails.
+    pub fn disconnect(&self) {
+        let mut state = self.state();
+        state.connected = false;
+        state.generation += 1;
+        drop(state);
+        self.shared.input_available.notify_all();
+    }
+
+    /// Simulate plugging the device back in.
+    ///
+    /// Handles opened before the device was disconnected stay unusable, as
+    /// with real hardware the device has to be opened again.
+    pub fn connect(&self) {
+        self.state().connected = true;
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.state().connected
+    }
+}
+
+/// An open handle to a [`MockDevice`].
+struct MockHandle {
+    device: MockDevice,
+    generation: u64,
+    blocking: AtomicBool,
+    last_error: Mutex<Option<String>>,
+}
+
+impl MockHandle {
+    fn new(device: MockDevice) -> Self {
+        let generation = device.state().generation;
+        MockHandle {
+            device,
+            generation,
+            blocking: AtomicBool::new(true),
+            last_error: Mutex::new(None),
+        }
+    }
+
+    fn fail<T>(&self, message: String) -> HidResult<T> {
+        *self.last_error.lock().unwrap() = Some(message.clone());
+        Err(mock_error(message))
+    }
+
+    /// Copy the report with the ID in `buf[0]` from `reports` into `buf`.
+    fn get_report<F>(&self, kind: &str, buf: &mut [u8], reports: F) -> HidResult<usize>
+    where
+        F: FnOnce(&MockState) -> &HashMap<u8, Vec<u8>>,
+    {
+        let state = self.connected_state()?;
+        let report_id = buf.first().copied().unwrap_or(0);
+        match reports(&state).get(&report_id) {
+            Some(report) => {
+                let len = report.len().min(buf.len());
+                buf[..len].copy_from_slice(&report[..len]);
+                Ok(len)
+            }
+            None => {
+                drop(state);
+                self.fail(format!("mock: no {} report with id {}", kind, report_id))
+            }
+        }
+    }
+
+    /// Lock the device state, failing if the device was disconnected since
+    /// this handle was opened.
+    fn connected_state(&self) -> HidResult<MutexGuard<'_, MockState>> {
+        let state = self.device.state();
+        if !state.connected || state.generation != self.generation {
+            drop(state);
+            return self.fail("mock: device disconnected".into());
+        }
+        Ok(state)
+    }
+}
+
+impl HidDeviceBackend for MockHandle {
+    fn check_error(&self) -> HidResult<HidError> {
+        match *self.last_error.lock().unwrap() {
+            Some(ref message) => Ok(mock_error(message.clone())),
+            None => Err(HidError::HidApiErrorEmpty),
+        }
+    }
+
+    fn write(&self, data: &[u8]) -> HidResult<usize> {
+        let mut state = self.connected_state()?;
+
+        let reply = match state.expected_writes.pop_front() {
+            Some((ref expected, ref reply)) if expected.as_slice() == data => reply.clone(),
+            Some(expectation) => {
+                let message = format!(
+                    "mock: unexpected write {:02x?}, expected {:02x?}",
+                    data, expectation.0
+                );
+                state.expected_writes.push_front(expectation);
+                drop(state);
+                return self.fail(message);
+            }
+            None => None,
+        };
+
+        state.writes.push(data.to_vec());
+        if let Some(reply) = reply {
+            state.input_reports.push_back(reply);
+            self.device.shared.input_available.notify_all();
+        }
+        Ok(data.len())
+    }
+
+    fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let timeout = if self.blocking.load(Ordering::Relaxed) {
+            -1
+        } else {
+            0
+        };
+        self.read_timeout(buf, timeout)
+    }
+
+    fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
+        let deadline = if timeout < 0 {
+            None
+        } else {
+            Some(Instant::now() + Duration::from_millis(timeout as u64))
+        };
+
+        let mut state = self.connected_state()?;
+        loop {
+            if let Some(report) = state.input_reports.pop_front() {
+                let len = report.len().min(buf.len());
+                buf[..len].copy_from_slice(&report[..len]);
+                return Ok(len);
+            }
+
+            state = match deadline {
+                None => self.device.shared.input_available.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(0);
+                    }
+                    self.device
+                        .shared
+                        .input_available
+                        .wait_timeout(state, deadline - now)
+                        .unwrap()
+                        .0
+                }
+            };
+
+            if !state.connected || state.generation != self.generation {
+                drop(state);
+                return self.fail("mock: device disconnected".into());
+            }
+        }
+    }
+
+    fn send_feature_report(&self, data: &[u8]) -> HidResult<usize> {
+        let mut state = self.connected_state()?;
+        state.sent_feature_reports.push(data.to_vec());
+        Ok(data.len())
+    }
+
+    fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.get_report("feature", buf, |state| &state.feature_reports)
+    }
+
+    fn get_input_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.get_report("input", buf, |state| &state.requested_input_reports)
+    }
+
+    fn set_blocking_mode(&self, blocking: bool) -> HidResult<()> {
+        self.blocking.store(blocking, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn get_manufacturer_string(&self) -> HidResult<Option<String>> {
+        Ok(self
+            .connected_state()?
+            .info
+            .manufacturer_string()
+            .map(str::to_owned))
+    }
+
+    fn get_product_string(&self) -> HidResult<Option<String>> {
+        Ok(self
+            .connected_state()?
+            .info
+            .product_string()
+            .map(str::to_owned))
+    }
+
+    fn get_serial_number_string(&self) -> HidResult<Option<String>> {
+        Ok(self
+            .connected_state()?
+            .info
+            .serial_number()
+            .map(str::to_owned))
+    }
+
+    fn get_indexed_string(&self, index: i32) -> HidResult<Option<String>> {
+        Ok(self.connected_state()?.indexed_strings.get(&index).cloned())
+    }
+
+    fn get_device_info(&self) -> HidResult<DeviceInfo> {
+        Ok(self.connected_state()?.info.clone())
+    }
+
+    fn get_report_descriptor(&self) -> HidResult<Vec<u8>> {
+        let descriptor = self.connected_state()?.report_descriptor.clone();
+        match descriptor {
+            Some(descriptor) => Ok(descriptor),
+            None => self.fail("mock: no report descriptor set".into()),
+        }
+    }
+
+    fn into_sync(self: Box<Self>) -> Result<SyncDeviceBackend, Box<dyn HidDeviceBackend>> {
+        Ok(self)
+    }
+}
@@ -0,0 +1,88 @@
+//! Periodic sampling of input reports, see [`HidDevice::poll_input_report()`].
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{HidDevice, HidResult};
+
+impl HidDevice {
+    /// Request the input report `report_id` once per `interval`, see
+    /// [`HidDevice::get_input_report()`].
+    ///
+    /// The returned iterator blocks until the next sample is due. Samples are
+    /// scheduled at fixed multiples of `interval` after the first one, so slow
+    /// requests do not make the schedule drift. If a request takes longer than
+    /// `interval`, the samples which were missed are skipped. `len` is the
+    /// size of the report including the report ID.
+    ///
+    /// ```
+    /// use hidapi::mock::{MockBackend, MockDevice};
+    /// use hidapi::HidApi;
+    /// use std::time::Duration;
+    ///
+    /// let mock = MockDevice::new(0x1234, 0x5678);
+    /// mock.set_input_report(&[0x05, 0x2A]);
+    /// let backend = MockBackend::new();
+    /// backend.add_device(&mock);
+    /// let device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+    ///
+    /// let samples: Vec<_> = device
+    ///     .poll_input_report(0x05, 2, Duration::from_millis(5))
+    ///     .take(3)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(samples, vec![vec![0x05, 0x2A]; 3]);
+    /// ```
+    pub fn poll_input_report(
+        &self,
+        report_id: u8,
+        len: usize,
+        interval: Duration,
+    ) -> InputReportPoller<'_> {
+        InputReportPoller {
+            device: self,
+            report_id,
+            buf: vec![0u8; len],
+            interval,
+            next: None,
+        }
+    }
+}
+
+/// Iterator over periodically requested input reports, see
+/// [`HidDevice::poll_input_report()`].
+///
+/// Every item is one report, starting with the report ID. The iterator never
+/// ends, errors are returned as items as well.
+pub struct InputReportPoller<'a> {
+    device: &'a HidDevice,
+    report_id: u8,
+    buf: Vec<u8>,
+    interval: Duration,
+    /// When the next sample is due, `None` before the first sample.
+    next: Option<Instant>,
+}
+
+impl Iterator for InputReportPoller<'_> {
+    type Item = HidResult<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let now = Instant::now();
+        let due = match self.next {
+            None => now,
+            Some(due) if due > now => {
+                thread::sleep(due - now);
+                due
+            }
+            Some(due) => {
+                // Skip the samples, which are already overdue
+                let behind = (now - due).as_nanos() / self.interval.as_nanos().max(1);
+                due + self.interval * behind.min(u32::MAX as u128) as u32
+            }
+        };
+        self.next = Some(due + self.interval);
+
+        let res = self.device.get_input_report(self.report_id, &mut self.buf);
+        Some(res.map(|len| self.buf[..len].to_vec()))
+    }
+}
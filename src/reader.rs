@@ -0,0 +1,394 @@
+//! Background thread reading input reports, see [`HidDevice::spawn_reader()`].
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{RecvTimeoutError, TryRecvError};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+#[cfg(any(feature = "tokio", feature = "futures"))]
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::{HidDevice, HidReader, HidResult, HidWriter, MAX_REPORT_LEN};
+
+/// Timeout of a single read of the reader thread, after which it checks
+/// whether its receiver is still alive.
+const READ_SLICE_MS: i32 = 100;
+
+/// What the reader thread does with a report, when the channel is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Drop the oldest report in the channel, to make room for the new one.
+    DropOldest,
+    /// Drop the new report.
+    DropNewest,
+    /// Stop reading until there is room. Reports may then be lost in the
+    /// buffers of the OS instead.
+    #[default]
+    Block,
+}
+
+/// Configuration of [`HidDevice::spawn_reader()`].
+#[derive(Debug, Clone)]
+pub struct ReaderConfig {
+    capacity: usize,
+    overflow: OverflowPolicy,
+    report_size: usize,
+}
+
+impl Default for ReaderConfig {
+    fn default() -> Self {
+        ReaderConfig {
+            capacity: 64,
+            overflow: OverflowPolicy::Block,
+            report_size: MAX_REPORT_LEN,
+        }
+    }
+}
+
+impl ReaderConfig {
+    /// Create a configuration for a channel of 64 reports, which blocks when
+    /// it is full, and reports of up to 4096 bytes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of reports the channel holds. At least one report is held.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    pub fn overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Size of the read buffer, which has to fit the largest input report.
+    pub fn report_size(mut self, report_size: usize) -> Self {
+        self.report_size = report_size;
+        self
+    }
+}
+
+/// An input report with the time it was read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampedReport {
+    pub data: Vec<u8>,
+    pub timestamp: Instant,
+}
+
+struct QueueState {
+    reports: VecDeque<HidResult<TimestampedReport>>,
+    dropped: u64,
+    /// The [`BackgroundReader`] was dropped.
+    closed: bool,
+    /// The reader thread has stopped.
+    finished: bool,
+    /// The task waiting for the next report, if it is received asynchronously.
+    #[cfg(any(feature = "tokio", feature = "futures"))]
+    waker: Option<Waker>,
+}
+
+impl QueueState {
+    fn wake(&mut self) {
+        #[cfg(any(feature = "tokio", feature = "futures"))]
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct Queue {
+    state: Mutex<QueueState>,
+    changed: Condvar,
+    capacity: usize,
+    overflow: OverflowPolicy,
+}
+
+impl Queue {
+    fn state(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap()
+    }
+
+    /// Hand a report over to the receiver. Returns `false` if the receiver is
+    /// gone.
+    fn push(&self, report: TimestampedReport) -> bool {
+        let mut state = self.state();
+        if state.reports.len() >= self.capacity {
+            match self.overflow {
+                OverflowPolicy::DropOldest => {
+                    state.reports.pop_front();
+                    state.dropped += 1;
+                }
+                OverflowPolicy::DropNewest => {
+                    state.dropped += 1;
+                    return !state.closed;
+                }
+                OverflowPolicy::Block => {
+                    while state.reports.len() >= self.capacity && !state.closed {
+                        state = self.changed.wait(state).unwrap();
+                    }
+                }
+            }
+        }
+        if state.closed {
+            return false;
+        }
+
+        state.reports.push_back(Ok(report));
+        state.wake();
+        self.changed.notify_all();
+        true
+    }
+
+    /// Mark the reader thread as stopped, delivering the error that stopped
+    /// it regardless of the capacity.
+    fn finish(&self, res: HidResult<()>) {
+        let mut state = self.state();
+        if let Err(err) = res {
+            state.reports.push_back(Err(err));
+        }
+        state.finished = true;
+        state.wake();
+        self.changed.notify_all();
+    }
+
+    fn close(&self) {
+        self.state().closed = true;
+        self.changed.notify_all();
+    }
+
+    #[cfg(any(feature = "tokio", feature = "futures"))]
+    fn poll_pop(&self, cx: &mut Context<'_>) -> Poll<Option<HidResult<TimestampedReport>>> {
+        let mut state = self.state();
+        if let Some(report) = state.reports.pop_front() {
+            self.changed.notify_all();
+            return Poll::Ready(Some(report));
+        }
+        if state.finished {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Waits up to `timeout` for the next report, or forever if it is `None`.
+    fn pop(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<HidResult<TimestampedReport>, RecvTimeoutError> {
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let mut state = self.state();
+        loop {
+            if let Some(report) = state.reports.pop_front() {
+                self.changed.notify_all();
+                return Ok(report);
+            }
+            if state.finished {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+
+            state = match deadline {
+                None => self.changed.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(RecvTimeoutError::Timeout);
+                    }
+                    self.changed.wait_timeout(state, deadline - now).unwrap().0
+                }
+            };
+        }
+    }
+}
+
+impl HidDevice {
+    /// Start a thread, which reads input reports and delivers them through a
+    /// bounded channel.
+    ///
+    /// The device is split with [`HidDevice::split()`], the writer half stays
+    /// available through [`BackgroundReader::writer()`]. The thread stops when
+    /// the [`BackgroundReader`] is dropped, or after a read failed, e.g.
+    /// because the device was disconnected. The error is delivered as the last
+    /// item of the channel.
+    ///
+    /// ```
+    /// use hidapi::mock::{MockBackend, MockDevice};
+    /// use hidapi::{HidApi, OverflowPolicy, ReaderConfig};
+    ///
+    /// let mock = MockDevice::new(0x1234, 0x5678);
+    /// let backend = MockBackend::new();
+    /// backend.add_device(&mock);
+    /// let device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+    ///
+    /// let config = ReaderConfig::new()
+    ///     .capacity(16)
+    ///     .overflow(OverflowPolicy::DropOldest);
+    /// let mut reader = device.spawn_reader(config).unwrap();
+    ///
+    /// mock.push_input_report(&[0x01, 0x02]);
+    /// let report = reader.next().unwrap().unwrap();
+    /// assert_eq!(report.data, vec![0x01, 0x02]);
+    ///
+    /// // Unplugging the device ends the channel with an error
+    /// mock.disconnect();
+    /// assert!(reader.next().unwrap().is_err());
+    /// assert!(reader.next().is_none());
+    /// ```
+    pub fn spawn_reader(self, config: ReaderConfig) -> HidResult<BackgroundReader> {
+        let (reader, writer) = self.split();
+        Ok(BackgroundReader {
+            reports: ReportThread::spawn(reader, config)?,
+            writer,
+        })
+    }
+}
+
+/// The reader thread and the channel it fills.
+///
+/// Also used by the async wrappers, for backends which offer no pollable
+/// handle.
+pub(crate) struct ReportThread {
+    queue: Arc<Queue>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ReportThread {
+    pub(crate) fn spawn(reader: HidReader, config: ReaderConfig) -> HidResult<Self> {
+        let queue = Arc::new(Queue {
+            state: Mutex::new(QueueState {
+                reports: VecDeque::with_capacity(config.capacity),
+                dropped: 0,
+                closed: false,
+                finished: false,
+                #[cfg(any(feature = "tokio", feature = "futures"))]
+                waker: None,
+            }),
+            changed: Condvar::new(),
+            capacity: config.capacity,
+            overflow: config.overflow,
+        });
+
+        let thread_queue = queue.clone();
+        let thread = thread::Builder::new()
+            .name("hidapi-reader".into())
+            .spawn(move || {
+                let res = read_reports(&reader, &thread_queue, config.report_size);
+                thread_queue.finish(res);
+            })?;
+
+        Ok(ReportThread {
+            queue,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the next report, or `None` once the thread has stopped. Only
+    /// the task which polled last is woken up.
+    #[cfg(any(feature = "tokio", feature = "futures"))]
+    pub(crate) fn poll_next(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<HidResult<TimestampedReport>>> {
+        self.queue.poll_pop(cx)
+    }
+
+    /// Stops the thread and waits for it to finish its current read.
+    fn join(&mut self) {
+        self.queue.close();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ReportThread {
+    /// Stops the thread without waiting for it. It releases the device once
+    /// its current read has finished.
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+fn read_reports(reader: &HidReader, queue: &Queue, report_size: usize) -> HidResult<()> {
+    let mut buf = vec![0u8; report_size];
+    while !queue.state().closed {
+        let len = reader.read_timeout(&mut buf, READ_SLICE_MS)?;
+        if len == 0 {
+            continue;
+        }
+
+        let report = TimestampedReport {
+            data: buf[..len].to_vec(),
+            timestamp: Instant::now(),
+        };
+        if !queue.push(report) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Receiving end of the reports read by [`HidDevice::spawn_reader()`].
+///
+/// The iterator blocks until the next report. It ends after an error.
+pub struct BackgroundReader {
+    reports: ReportThread,
+    writer: HidWriter,
+}
+
+impl BackgroundReader {
+    /// Returns the next report without blocking.
+    ///
+    /// Fails with [`TryRecvError::Empty`] if there is no report right now, and
+    /// with [`TryRecvError::Disconnected`] once the reader has stopped and all
+    /// reports were received.
+    pub fn try_next(&self) -> Result<HidResult<TimestampedReport>, TryRecvError> {
+        self.reports
+            .queue
+            .pop(Some(Duration::ZERO))
+            .map_err(|err| match err {
+                RecvTimeoutError::Timeout => TryRecvError::Empty,
+                RecvTimeoutError::Disconnected => TryRecvError::Disconnected,
+            })
+    }
+
+    /// Waits up to `timeout` for the next report.
+    ///
+    /// Fails with [`RecvTimeoutError::Timeout`] if no report arrived in time,
+    /// and with [`RecvTimeoutError::Disconnected`] once the reader has stopped
+    /// and all reports were received.
+    pub fn recv_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<HidResult<TimestampedReport>, RecvTimeoutError> {
+        self.reports.queue.pop(Some(timeout))
+    }
+
+    /// Number of reports dropped so far, because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.reports.queue.state().dropped
+    }
+
+    /// The writing half of the device.
+    pub fn writer(&self) -> &HidWriter {
+        &self.writer
+    }
+}
+
+impl Iterator for BackgroundReader {
+    type Item = HidResult<TimestampedReport>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reports.queue.pop(None).ok()
+    }
+}
+
+impl Drop for BackgroundReader {
+    /// Waits for the reader thread to stop, which takes up to 100 ms, so the
+    /// device is closed once the `BackgroundReader` is gone.
+    fn drop(&mut self) {
+        self.reports.join();
+    }
+}
@@ -0,0 +1,384 @@
+//! A device handle, which survives the device being unplugged and replugged.
+//!
+//! [`HidApi::reconnecting()`] creates a [`ReconnectBuilder`] for a
+//! [`ReconnectingDevice`], which remembers how to find the device. When an
+//! operation fails because the device went away, the handle is closed. The
+//! next operation opens the device again, once it is back, and sends the
+//! initialisation reports configured with the builder before anything else.
+//!
+//! ```
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::reconnect::{ConnectionState, DeviceSelector};
+//! use hidapi::{ErrorKind, HidApi};
+//! use std::sync::mpsc;
+//! use std::time::Duration;
+//!
+//! let mock = MockDevice::new(0x1234, 0x5678);
+//! let backend = MockBackend::new();
+//! backend.add_device(&mock);
+//! let api = HidApi::with_backend(backend).unwrap();
+//!
+//! let (sender, states) = mpsc::channel();
+//! let device = api
+//!     .reconnecting(DeviceSelector::Ids {
+//!         vendor_id: 0x1234,
+//!         product_id: 0x5678,
+//!         serial_number: None,
+//!     })
+//!     .init_write(&[0x00, 0x01])
+//!     .retry_interval(Duration::ZERO)
+//!     .on_state_change(move |state| sender.send(state).unwrap())
+//!     .open();
+//! assert_eq!(device.state(), ConnectionState::Connected);
+//!
+//! mock.disconnect();
+//! let err = device.write(&[0x00, 0x02]).unwrap_err();
+//! assert_eq!(err.kind(), ErrorKind::Disconnected);
+//!
+//! mock.connect();
+//! device.write(&[0x00, 0x03]).unwrap();
+//! // The initialisation report was sent again after reconnecting
+//! assert_eq!(mock.writes(), vec![vec![0x00, 0x01], vec![0x00, 0x01], vec![0x00, 0x03]]);
+//!
+//! let states: Vec<_> = states.try_iter().collect();
+//! assert_eq!(
+//!     states,
+//!     vec![ConnectionState::Connected, ConnectionState::Disconnected, ConnectionState::Connected]
+//! );
+//! ```
+
+use std::ffi::CString;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{DeviceFilter, ErrorKind, HidApiBackend, HidDevice, HidError, HidResult};
+
+#[cfg(doc)]
+use crate::HidApi;
+
+/// Default of [`ReconnectBuilder::retry_interval()`].
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How a [`ReconnectingDevice`] finds its device.
+#[derive(Debug, Clone)]
+pub enum DeviceSelector {
+    /// Open the device at a fixed path, see [`HidApi::open_path()`].
+    ///
+    /// Some platforms assign a new path to a replugged device, e.g. another
+    /// `/dev/hidraw*` node on linux, so the other selectors are more reliable.
+    Path(CString),
+    /// Open the first device with the given ids and, if set, serial number.
+    Ids {
+        vendor_id: u16,
+        product_id: u16,
+        serial_number: Option<String>,
+    },
+    /// Open the first device matching the filter.
+    Filter(DeviceFilter),
+}
+
+/// Whether a [`ReconnectingDevice`] currently has an open handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,
+    Disconnected,
+}
+
+enum InitReport {
+    Output(Vec<u8>),
+    Feature(Vec<u8>),
+}
+
+type StateCallback = Box<dyn FnMut(ConnectionState) + Send>;
+
+/// Configures a [`ReconnectingDevice`], see the [module documentation](self).
+pub struct ReconnectBuilder {
+    backend: Arc<dyn HidApiBackend>,
+    selector: DeviceSelector,
+    init: Vec<InitReport>,
+    retry_interval: Duration,
+    on_state_change: Option<StateCallback>,
+}
+
+impl ReconnectBuilder {
+    pub(crate) fn new(backend: Arc<dyn HidApiBackend>, selector: DeviceSelector) -> Self {
+        ReconnectBuilder {
+            backend,
+            selector,
+            init: Vec::new(),
+            retry_interval: RETRY_INTERVAL,
+            on_state_change: None,
+        }
+    }
+
+    /// Write an output report every time the device was opened, see
+    /// [`HidDevice::write()`].
+    ///
+    /// Initialisation reports are sent in the order they were added.
+    pub fn init_write(mut self, data: &[u8]) -> Self {
+        self.init.push(InitReport::Output(data.to_vec()));
+        self
+    }
+
+    /// Send a feature report every time the device was opened, see
+    /// [`HidDevice::send_feature_report()`].
+    pub fn init_feature_report(mut self, data: &[u8]) -> Self {
+        self.init.push(InitReport::Feature(data.to_vec()));
+        self
+    }
+
+    /// Minimum time between two attempts to open the device. Defaults to 1 s.
+    pub fn retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// Call `callback` whenever the device was opened or closed.
+    ///
+    /// The callback is called by the operation, which noticed the change,
+    /// after it released the device, so other threads are not held up by it.
+    /// It must not use the [`ReconnectingDevice`] itself.
+    pub fn on_state_change<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(ConnectionState) + Send + 'static,
+    {
+        self.on_state_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Create the device handle and try to open the device once.
+    ///
+    /// The device does not need to be attached yet, it is opened by the first
+    /// operation after it arrived.
+    pub fn open(self) -> ReconnectingDevice {
+        let device = ReconnectingDevice {
+            backend: self.backend,
+            selector: self.selector,
+            init: self.init,
+            retry_interval: self.retry_interval,
+            connection: Mutex::new(Connection {
+                device: None,
+                last_attempt: None,
+            }),
+            on_state_change: Mutex::new(self.on_state_change),
+        };
+        let mut connection = device.connection();
+        if device.connect(&mut connection).is_ok() {
+            device.notify(connection, &[ConnectionState::Connected]);
+        } else {
+            drop(connection);
+        }
+        device
+    }
+}
+
+struct Connection {
+    device: Option<HidDevice>,
+    last_attempt: Option<Instant>,
+}
+
+impl Connection {
+    /// Time until the next attempt to open the device is due.
+    fn until_next_attempt(&self, retry_interval: Duration) -> Duration {
+        match self.last_attempt {
+            Some(last_attempt) => retry_interval.saturating_sub(last_attempt.elapsed()),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+/// A device handle, which reopens the device after it was replugged.
+///
+/// Operations fail with an error of kind [`ErrorKind::Disconnected`], when the
+/// device went away, and with the error of opening it while it can not be
+/// opened again. Opening is attempted once per retry interval, so polling
+/// operations in a loop does not enumerate devices all the time. In between,
+/// operations fail with [`HidError::NotConnected`].
+///
+/// Operations are serialized, so a blocking read delays writes until it
+/// returns.
+pub struct ReconnectingDevice {
+    backend: Arc<dyn HidApiBackend>,
+    selector: DeviceSelector,
+    init: Vec<InitReport>,
+    retry_interval: Duration,
+    connection: Mutex<Connection>,
+    on_state_change: Mutex<Option<StateCallback>>,
+}
+
+impl ReconnectingDevice {
+    pub fn state(&self) -> ConnectionState {
+        match self.connection().device {
+            Some(_) => ConnectionState::Connected,
+            None => ConnectionState::Disconnected,
+        }
+    }
+
+    /// Wait until the device is open, trying to open it once per retry
+    /// interval, for at most `timeout` or forever if it is `None`.
+    ///
+    /// Returns the error of the last attempt, as soon as the next attempt
+    /// would be too late.
+    pub fn wait_connected(&self, timeout: Option<Duration>) -> HidResult<()> {
+        // A timeout too large for an `Instant` waits forever
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let mut last_err = HidError::NotConnected;
+        loop {
+            let mut connection = self.connection();
+            if connection.device.is_some() {
+                return Ok(());
+            }
+            if connection.until_next_attempt(self.retry_interval).is_zero() {
+                match self.connect(&mut connection) {
+                    Ok(()) => {
+                        self.notify(connection, &[ConnectionState::Connected]);
+                        return Ok(());
+                    }
+                    Err(err) => last_err = err,
+                }
+            }
+
+            let wait = connection.until_next_attempt(self.retry_interval);
+            drop(connection);
+            if deadline
+                .is_some_and(|deadline| deadline.saturating_duration_since(Instant::now()) <= wait)
+            {
+                return Err(last_err);
+            }
+            thread::sleep(wait);
+        }
+    }
+
+    /// Run `f` with the open device, opening it first if necessary.
+    ///
+    /// Returns the error of opening the device, if that failed, and
+    /// [`HidError::NotConnected`] if the next attempt is not due yet. If `f`
+    /// fails with an error of kind [`ErrorKind::Disconnected`], the device is
+    /// closed.
+    pub fn with_device<T, F>(&self, f: F) -> HidResult<T>
+    where
+        F: FnOnce(&HidDevice) -> HidResult<T>,
+    {
+        let mut connection = self.connection();
+        let mut changes = Vec::new();
+        if connection.device.is_none() {
+            if !connection.until_next_attempt(self.retry_interval).is_zero() {
+                return Err(HidError::NotConnected);
+            }
+            self.connect(&mut connection)?;
+            changes.push(ConnectionState::Connected);
+        }
+
+        let res = f(connection.device.as_ref().unwrap());
+        if res
+            .as_ref()
+            .is_err_and(|err| err.kind() == ErrorKind::Disconnected)
+        {
+            connection.device = None;
+            changes.push(ConnectionState::Disconnected);
+        }
+        self.notify(connection, &changes);
+        res
+    }
+
+    /// See [`HidDevice::write()`].
+    pub fn write(&self, data: &[u8]) -> HidResult<usize> {
+        self.with_device(|device| device.write(data))
+    }
+
+    /// See [`HidDevice::read()`].
+    pub fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.with_device(|device| device.read(buf))
+    }
+
+    /// See [`HidDevice::read_timeout()`].
+    pub fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
+        self.with_device(|device| device.read_timeout(buf, timeout))
+    }
+
+    /// See [`HidDevice::send_feature_report()`].
+    pub fn send_feature_report(&self, data: &[u8]) -> HidResult<()> {
+        self.with_device(|device| device.send_feature_report(data))
+    }
+
+    /// See [`HidDevice::get_feature_report()`].
+    pub fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.with_device(|device| device.get_feature_report(buf))
+    }
+
+    /// See [`HidDevice::get_input_report()`].
+    pub fn get_input_report(&self, report_id: u8, buf: &mut [u8]) -> HidResult<usize> {
+        self.with_device(|device| device.get_input_report(report_id, buf))
+    }
+
+    fn connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().unwrap()
+    }
+
+    /// Open the device and send the initialisation reports.
+    ///
+    /// The caller reports the new state with [`ReconnectingDevice::notify()`].
+    fn connect(&self, connection: &mut Connection) -> HidResult<()> {
+        connection.last_attempt = Some(Instant::now());
+
+        let device = self.open_device()?;
+        for report in &self.init {
+            match report {
+                InitReport::Output(data) => {
+                    device.write(data)?;
+                }
+                InitReport::Feature(data) => device.send_feature_report(data)?,
+            }
+        }
+
+        connection.device = Some(device);
+        Ok(())
+    }
+
+    fn open_device(&self) -> HidResult<HidDevice> {
+        let inner = match self.selector {
+            DeviceSelector::Path(ref path) => self.backend.open_path(path)?,
+            DeviceSelector::Ids {
+                vendor_id,
+                product_id,
+                serial_number: None,
+            } => self.backend.open(vendor_id, product_id)?,
+            DeviceSelector::Ids {
+                vendor_id,
+                product_id,
+                serial_number: Some(ref serial_number),
+            } => self
+                .backend
+                .open_serial(vendor_id, product_id, serial_number)?,
+            DeviceSelector::Filter(ref filter) => {
+                let (vendor_id, product_id) = filter.ids();
+                let info = self
+                    .backend
+                    .enumerate(vendor_id, product_id)?
+                    .into_iter()
+                    .find(|info| filter.matches(info))
+                    .ok_or(HidError::NotConnected)?;
+                self.backend.open_path(info.path())?
+            }
+        };
+        Ok(HidDevice::new(inner))
+    }
+
+    /// Release the connection, then pass the state changes to the callback.
+    fn notify(&self, connection: MutexGuard<'_, Connection>, changes: &[ConnectionState]) {
+        if changes.is_empty() {
+            return;
+        }
+
+        // Locked before the connection is released, so concurrent operations
+        // report their changes in the order they happened
+        let mut callback = self.on_state_change.lock().unwrap();
+        drop(connection);
+        if let Some(callback) = callback.as_mut() {
+            for &state in changes {
+                callback(state);
+            }
+        }
+    }
+}
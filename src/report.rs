@@ -0,0 +1,307 @@
+//! Report buffers, which keep the report ID apart from the report data.
+//!
+//! The slice based methods of [`HidDevice`] expect the report ID in the first
+//! byte of the buffer, so a buffer is one byte longer than the report, and
+//! input reports only start with the report ID if the device uses numbered
+//! reports. [`OutputReport`], [`FeatureReport`] and [`InputReport`] take care
+//! of that, and are accepted by the methods of [`HidDevice`], [`HidReader`]
+//! and [`HidWriter`] named after them, e.g. [`HidDevice::write_output()`].
+//!
+//! ```
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::report::{FeatureReport, InputReport, OutputReport};
+//! use hidapi::{HidApi, ReadOutcome};
+//!
+//! let mock = MockDevice::new(0x1234, 0x5678);
+//! mock.set_feature_report(&[0x02, 0x10, 0x20]);
+//! let backend = MockBackend::new();
+//! backend.add_device(&mock);
+//! let device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+//!
+//! // A device without numbered reports uses report ID 0
+//! device.write_output(&OutputReport::new(0, &[0x01, 0x02])).unwrap();
+//! assert_eq!(mock.writes(), vec![vec![0x00, 0x01, 0x02]]);
+//!
+//! let mut feature = FeatureReport::with_len(0x02, Some(2));
+//! device.get_feature(&mut feature).unwrap();
+//! assert_eq!(feature.payload(), &[0x10, 0x20]);
+//!
+//! // Input reports of devices without numbered reports lack the report ID
+//! mock.push_input_report(&[0x03, 0x04]);
+//! let mut input = InputReport::with_len(None, Some(2));
+//! assert_eq!(device.read_input(&mut input).unwrap(), ReadOutcome::Report(2));
+//! assert_eq!(input.payload(), &[0x03, 0x04]);
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crate::descriptor::Report;
+use crate::{HidDevice, HidError, HidReader, HidResult, HidWriter, ReadOutcome, MAX_REPORT_LEN};
+
+/// Payload length used, when the length of a report is not known.
+const MAX_PAYLOAD_LEN: usize = MAX_REPORT_LEN - 1;
+
+/// Implements the accessors shared by all report types. With `constructors`,
+/// also the constructors of the report types, which are sent to the device.
+macro_rules! report_accessors {
+    ($name:ident, constructors) => {
+        impl $name {
+            pub fn new(report_id: u8, payload: &[u8]) -> Self {
+                let mut buf = Vec::with_capacity(payload.len() + 1);
+                buf.push(report_id);
+                buf.extend_from_slice(payload);
+                $name {
+                    len: buf.len(),
+                    buf,
+                }
+            }
+
+            /// A report with `len` bytes of data, which are all zero. If the
+            /// length is not known, the report is large enough for any report
+            /// (4095 bytes).
+            pub fn with_len(report_id: u8, len: Option<usize>) -> Self {
+                $name::new(report_id, &vec![0u8; len.unwrap_or(MAX_PAYLOAD_LEN)])
+            }
+
+            /// A zeroed report with the ID and length declared by the report
+            /// descriptor, see [`HidDevice::get_report_layout()`].
+            pub fn for_report(report: &Report) -> Self {
+                $name::with_len(report.report_id().unwrap_or(0), Some(report.byte_len()))
+            }
+        }
+
+        report_accessors!($name);
+    };
+    ($name:ident) => {
+        impl $name {
+            /// The report ID, which is 0 for devices without numbered reports.
+            pub fn report_id(&self) -> u8 {
+                self.buf[0]
+            }
+
+            /// The report data without the report ID.
+            pub fn payload(&self) -> &[u8] {
+                &self.buf[1..self.len]
+            }
+
+            pub fn payload_mut(&mut self) -> &mut [u8] {
+                &mut self.buf[1..self.len]
+            }
+
+            /// The report ID followed by the report data, as passed to the
+            /// slice based methods of [`HidDevice`].
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.buf[..self.len]
+            }
+        }
+    };
+}
+
+/// An output report, see [`HidDevice::write_output()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputReport {
+    buf: Vec<u8>,
+    len: usize,
+}
+
+report_accessors!(OutputReport, constructors);
+
+/// A feature report, see [`HidDevice::send_feature()`] and
+/// [`HidDevice::get_feature()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureReport {
+    buf: Vec<u8>,
+    len: usize,
+}
+
+impl FeatureReport {
+    /// Let `get()` fill the report, shortening it to the received length.
+    fn receive_with<F>(&mut self, get: F) -> HidResult<()>
+    where
+        F: FnOnce(&mut [u8]) -> HidResult<usize>,
+    {
+        let len = get(&mut self.buf)?;
+        // Keep the report ID, even if nothing was received
+        self.len = len.clamp(1, self.buf.len());
+        Ok(())
+    }
+}
+
+report_accessors!(FeatureReport, constructors);
+
+/// An input report, see [`HidDevice::read_input()`] and
+/// [`HidDevice::get_input()`].
+///
+/// Reading fills the report up to its length and shortens it to the length of
+/// the report, which was received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputReport {
+    buf: Vec<u8>,
+    len: usize,
+    /// Whether reads return the report ID.
+    numbered: bool,
+}
+
+impl InputReport {
+    /// A report with `len` bytes of data. If the length is not known, the
+    /// report is large enough for any report (4095 bytes).
+    ///
+    /// `report_id` is `None` for devices without numbered reports. Reading
+    /// replaces the report ID with the one of the received report.
+    pub fn with_len(report_id: Option<u8>, len: Option<usize>) -> Self {
+        let mut buf = vec![0u8; len.unwrap_or(MAX_PAYLOAD_LEN) + 1];
+        buf[0] = report_id.unwrap_or(0);
+        InputReport {
+            len: buf.len(),
+            buf,
+            numbered: report_id.is_some(),
+        }
+    }
+
+    /// A report with the ID and length declared by the report descriptor, see
+    /// [`HidDevice::get_report_layout()`].
+    pub fn for_report(report: &Report) -> Self {
+        InputReport::with_len(report.report_id(), Some(report.byte_len()))
+    }
+
+    /// Let `read()` fill the report, as [`HidDevice::read()`] would.
+    fn read_with<F>(&mut self, read: F) -> HidResult<ReadOutcome>
+    where
+        F: FnOnce(&mut [u8]) -> HidResult<ReadOutcome>,
+    {
+        let (outcome, offset) = if self.numbered {
+            (read(&mut self.buf)?, 0)
+        } else {
+            self.buf[0] = 0;
+            (read(&mut self.buf[1..])?, 1)
+        };
+
+        self.len = match outcome {
+            ReadOutcome::Report(len) => (len + offset).clamp(1, self.buf.len()),
+            ReadOutcome::TimedOut => 1,
+        };
+        Ok(match outcome {
+            ReadOutcome::Report(_) => ReadOutcome::Report(self.len - 1),
+            ReadOutcome::TimedOut => ReadOutcome::TimedOut,
+        })
+    }
+
+    /// Let `get()` fill the report, as [`HidDevice::get_input_report()`]
+    /// would.
+    fn receive_with<F>(&mut self, get: F) -> HidResult<()>
+    where
+        F: FnOnce(u8, &mut [u8]) -> HidResult<usize>,
+    {
+        let len = get(self.buf[0], &mut self.buf)?;
+        self.len = len.clamp(1, self.buf.len());
+        Ok(())
+    }
+}
+
+report_accessors!(InputReport);
+
+fn check_sent(sent: usize, all: usize) -> HidResult<()> {
+    // Some backends pad reports to their declared length
+    if sent < all {
+        Err(HidError::IncompleteSendError { sent, all })
+    } else {
+        Ok(())
+    }
+}
+
+impl HidDevice {
+    /// Write an output report, see [`HidDevice::write()`].
+    pub fn write_output(&self, report: &OutputReport) -> HidResult<()> {
+        check_sent(self.write(report.as_bytes())?, report.len)
+    }
+
+    /// Read an input report, see [`HidDevice::read()`].
+    ///
+    /// The outcome carries the length of the report data, without the report
+    /// ID.
+    pub fn read_input(&self, report: &mut InputReport) -> HidResult<ReadOutcome> {
+        report.read_with(|buf| Ok(ReadOutcome::from_len(self.read(buf)?)))
+    }
+
+    /// Read an input report, see [`HidDevice::read_with_timeout()`].
+    pub fn read_input_with_timeout(
+        &self,
+        report: &mut InputReport,
+        timeout: Option<Duration>,
+    ) -> HidResult<ReadOutcome> {
+        report.read_with(|buf| self.read_with_timeout(buf, timeout))
+    }
+
+    /// Read an input report, see [`HidDevice::read_until()`].
+    pub fn read_input_until(
+        &self,
+        report: &mut InputReport,
+        deadline: Instant,
+    ) -> HidResult<ReadOutcome> {
+        report.read_with(|buf| self.read_until(buf, deadline))
+    }
+
+    /// Request the input report with the ID of `report`, see
+    /// [`HidDevice::get_input_report()`].
+    pub fn get_input(&self, report: &mut InputReport) -> HidResult<()> {
+        report.receive_with(|report_id, buf| self.get_input_report(report_id, buf))
+    }
+
+    /// Send a feature report, see [`HidDevice::send_feature_report()`].
+    pub fn send_feature(&self, report: &FeatureReport) -> HidResult<()> {
+        self.send_feature_report(report.as_bytes())
+    }
+
+    /// Get the feature report with the ID of `report`, see
+    /// [`HidDevice::get_feature_report()`].
+    pub fn get_feature(&self, report: &mut FeatureReport) -> HidResult<()> {
+        report.receive_with(|buf| self.get_feature_report(buf))
+    }
+}
+
+impl HidReader {
+    /// See [`HidDevice::read_input()`].
+    pub fn read_input(&self, report: &mut InputReport) -> HidResult<ReadOutcome> {
+        report.read_with(|buf| Ok(ReadOutcome::from_len(self.read(buf)?)))
+    }
+
+    /// See [`HidDevice::read_input_with_timeout()`].
+    pub fn read_input_with_timeout(
+        &self,
+        report: &mut InputReport,
+        timeout: Option<Duration>,
+    ) -> HidResult<ReadOutcome> {
+        report.read_with(|buf| self.read_with_timeout(buf, timeout))
+    }
+
+    /// See [`HidDevice::read_input_until()`].
+    pub fn read_input_until(
+        &self,
+        report: &mut InputReport,
+        deadline: Instant,
+    ) -> HidResult<ReadOutcome> {
+        report.read_with(|buf| self.read_until(buf, deadline))
+    }
+}
+
+impl HidWriter {
+    /// See [`HidDevice::write_output()`].
+    pub fn write_output(&self, report: &OutputReport) -> HidResult<()> {
+        check_sent(self.write(report.as_bytes())?, report.len)
+    }
+
+    /// See [`HidDevice::get_input()`].
+    pub fn get_input(&self, report: &mut InputReport) -> HidResult<()> {
+        report.receive_with(|report_id, buf| self.get_input_report(report_id, buf))
+    }
+
+    /// See [`HidDevice::send_feature()`].
+    pub fn send_feature(&self, report: &FeatureReport) -> HidResult<()> {
+        self.send_feature_report(report.as_bytes())
+    }
+
+    /// See [`HidDevice::get_feature()`].
+    pub fn get_feature(&self, report: &mut FeatureReport) -> HidResult<()> {
+        report.receive_with(|buf| self.get_feature_report(buf))
+    }
+}
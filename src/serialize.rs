@@ -0,0 +1,305 @@
+//! `serde` support for [`DeviceInfo`] and [`HidError`], enabled by the `serde`
+//! feature.
+//!
+//! Both are converted to plain representations, which mirror their fields:
+//!
+//! - The path of a [`DeviceInfo`] is stored as lossy UTF-8 string `path`,
+//!   for display, and as the exact bytes `path_bytes`, which are used when
+//!   deserializing.
+//! - Strings, which could not be converted from wide characters, are kept as
+//!   raw `wchar_t` values in `serial_number_raw` and the like.
+//! - An [`HidError::IoError`] is stored as its [`ErrorKind`], OS error code and
+//!   message. Deserializing recreates the `io::Error` from the OS error code,
+//!   if there is one.
+//!
+//! ```
+//! use hidapi::descriptor::ReportDescriptor;
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::{DeviceInfo, HidApi, HidError};
+//!
+//! let backend = MockBackend::new();
+//! backend.add_device(&MockDevice::new(0x1234, 0x5678).with_serial_number("A-100"));
+//! let api = HidApi::with_backend(backend).unwrap();
+//! let info = api.device_list().next().unwrap();
+//!
+//! let json = serde_json::to_string(info).unwrap();
+//! let copy: DeviceInfo = serde_json::from_str(&json).unwrap();
+//! assert_eq!(copy.vendor_id(), 0x1234);
+//! assert_eq!(copy.serial_number(), Some("A-100"));
+//! assert_eq!(copy.path(), info.path());
+//!
+//! let err = api.open(0x1234, 0x0000).unwrap_err();
+//! let json = serde_json::to_string(&err).unwrap();
+//! let copy: HidError = serde_json::from_str(&json).unwrap();
+//! assert_eq!(copy.to_string(), err.to_string());
+//!
+//! let err = ReportDescriptor::parse(&[0xB4]).unwrap_err();
+//! let json = serde_json::to_string(&err).unwrap();
+//! let copy: HidError = serde_json::from_str(&json).unwrap();
+//! assert_eq!(copy.to_string(), "Invalid report descriptor at byte 0: pop without push");
+//! ```
+
+use libc::wchar_t;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ffi::{CString, NulError};
+use std::io;
+
+use crate::error::classify_io_error;
+use crate::{BusType, DeviceInfo, ErrorKind, HidError, WcharString};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DeviceInfo")]
+struct DeviceInfoRepr {
+    path: String,
+    path_bytes: Vec<u8>,
+    vendor_id: u16,
+    product_id: u16,
+    serial_number: Option<String>,
+    serial_number_raw: Option<Vec<wchar_t>>,
+    release_number: u16,
+    manufacturer_string: Option<String>,
+    manufacturer_string_raw: Option<Vec<wchar_t>>,
+    product_string: Option<String>,
+    product_string_raw: Option<Vec<wchar_t>>,
+    usage_page: u16,
+    usage: u16,
+    interface_number: i32,
+    bus_type: BusType,
+}
+
+/// Split a string into its converted and raw representation.
+fn split_wchar(s: &WcharString) -> (Option<String>, Option<Vec<wchar_t>>) {
+    match s {
+        WcharString::String(s) => (Some(s.clone()), None),
+        WcharString::Raw(raw) => (None, Some(raw.clone())),
+        WcharString::None => (None, None),
+    }
+}
+
+fn join_wchar(s: Option<String>, raw: Option<Vec<wchar_t>>) -> WcharString {
+    match (s, raw) {
+        (Some(s), _) => WcharString::String(s),
+        (None, Some(raw)) => WcharString::Raw(raw),
+        (None, None) => WcharString::None,
+    }
+}
+
+impl From<&DeviceInfo> for DeviceInfoRepr {
+    fn from(info: &DeviceInfo) -> Self {
+        let (serial_number, serial_number_raw) = split_wchar(&info.serial_number);
+        let (manufacturer_string, manufacturer_string_raw) = split_wchar(&info.manufacturer_string);
+        let (product_string, product_string_raw) = split_wchar(&info.product_string);
+
+        DeviceInfoRepr {
+            path: info.path.to_string_lossy().into_owned(),
+            path_bytes: info.path.as_bytes().to_vec(),
+            vendor_id: info.vendor_id,
+            product_id: info.product_id,
+            serial_number,
+            serial_number_raw,
+            release_number: info.release_number,
+            manufacturer_string,
+            manufacturer_string_raw,
+            product_string,
+            product_string_raw,
+            usage_page: info.usage_page,
+            usage: info.usage,
+            interface_number: info.interface_number,
+            bus_type: info.bus_type,
+        }
+    }
+}
+
+impl TryFrom<DeviceInfoRepr> for DeviceInfo {
+    type Error = NulError;
+
+    fn try_from(repr: DeviceInfoRepr) -> Result<Self, NulError> {
+        Ok(DeviceInfo {
+            path: CString::new(repr.path_bytes)?,
+            vendor_id: repr.vendor_id,
+            product_id: repr.product_id,
+            serial_number: join_wchar(repr.serial_number, repr.serial_number_raw),
+            release_number: repr.release_number,
+            manufacturer_string: join_wchar(repr.manufacturer_string, repr.manufacturer_string_raw),
+            product_string: join_wchar(repr.product_string, repr.product_string_raw),
+            usage_page: repr.usage_page,
+            usage: repr.usage,
+            interface_number: repr.interface_number,
+            bus_type: repr.bus_type,
+        })
+    }
+}
+
+impl Serialize for DeviceInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DeviceInfoRepr::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = DeviceInfoRepr::deserialize(deserializer)?;
+        DeviceInfo::try_from(repr).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HidError")]
+enum HidErrorRepr {
+    HidApiError {
+        message: String,
+    },
+    HidApiErrorEmpty,
+    FromWideCharError {
+        wide_char: wchar_t,
+    },
+    InitializationError,
+    InitializationModeMismatch {
+        enumerate: bool,
+    },
+    DevicesStillOpen {
+        open: usize,
+    },
+    InvalidZeroSizeData,
+    IncompleteSendError {
+        sent: usize,
+        all: usize,
+    },
+    SetBlockingModeError {
+        mode: String,
+    },
+    OpenHidDeviceWithDeviceInfoError {
+        device_info: Box<DeviceInfo>,
+    },
+    IoError {
+        kind: ErrorKind,
+        raw_os_error: Option<i32>,
+        message: String,
+    },
+    InvalidReportDescriptor {
+        offset: usize,
+        reason: String,
+    },
+    InvalidReport {
+        message: String,
+    },
+    TransactionTimedOut {
+        attempts: u32,
+    },
+    NotConnected,
+}
+
+impl From<&HidError> for HidErrorRepr {
+    fn from(error: &HidError) -> Self {
+        match error {
+            HidError::HidApiError { message } => HidErrorRepr::HidApiError {
+                message: message.clone(),
+            },
+            HidError::HidApiErrorEmpty => HidErrorRepr::HidApiErrorEmpty,
+            HidError::FromWideCharError { wide_char } => HidErrorRepr::FromWideCharError {
+                wide_char: *wide_char,
+            },
+            HidError::InitializationError => HidErrorRepr::InitializationError,
+            HidError::InitializationModeMismatch { enumerate } => {
+                HidErrorRepr::InitializationModeMismatch {
+                    enumerate: *enumerate,
+                }
+            }
+            HidError::DevicesStillOpen { open } => HidErrorRepr::DevicesStillOpen { open: *open },
+            HidError::InvalidZeroSizeData => HidErrorRepr::InvalidZeroSizeData,
+            HidError::IncompleteSendError { sent, all } => HidErrorRepr::IncompleteSendError {
+                sent: *sent,
+                all: *all,
+            },
+            HidError::SetBlockingModeError { mode } => HidErrorRepr::SetBlockingModeError {
+                mode: mode.to_string(),
+            },
+            HidError::OpenHidDeviceWithDeviceInfoError { device_info } => {
+                HidErrorRepr::OpenHidDeviceWithDeviceInfoError {
+                    device_info: device_info.clone(),
+                }
+            }
+            HidError::IoError { error } => HidErrorRepr::IoError {
+                kind: classify_io_error(error),
+                raw_os_error: error.raw_os_error(),
+                message: error.to_string(),
+            },
+            HidError::InvalidReportDescriptor { offset, reason } => {
+                HidErrorRepr::InvalidReportDescriptor {
+                    offset: *offset,
+                    reason: reason.to_string(),
+                }
+            }
+            HidError::InvalidReport { message } => HidErrorRepr::InvalidReport {
+                message: message.clone(),
+            },
+            HidError::TransactionTimedOut { attempts } => HidErrorRepr::TransactionTimedOut {
+                attempts: *attempts,
+            },
+            HidError::NotConnected => HidErrorRepr::NotConnected,
+        }
+    }
+}
+
+impl From<HidErrorRepr> for HidError {
+    fn from(repr: HidErrorRepr) -> Self {
+        match repr {
+            HidErrorRepr::HidApiError { message } => HidError::HidApiError { message },
+            HidErrorRepr::HidApiErrorEmpty => HidError::HidApiErrorEmpty,
+            HidErrorRepr::FromWideCharError { wide_char } => {
+                HidError::FromWideCharError { wide_char }
+            }
+            HidErrorRepr::InitializationError => HidError::InitializationError,
+            HidErrorRepr::InitializationModeMismatch { enumerate } => {
+                HidError::InitializationModeMismatch { enumerate }
+            }
+            HidErrorRepr::DevicesStillOpen { open } => HidError::DevicesStillOpen { open },
+            HidErrorRepr::InvalidZeroSizeData => HidError::InvalidZeroSizeData,
+            HidErrorRepr::IncompleteSendError { sent, all } => {
+                HidError::IncompleteSendError { sent, all }
+            }
+            HidErrorRepr::SetBlockingModeError { mode } => HidError::SetBlockingModeError {
+                mode: match mode.as_str() {
+                    "blocking" => "blocking",
+                    _ => "not blocking",
+                },
+            },
+            HidErrorRepr::OpenHidDeviceWithDeviceInfoError { device_info } => {
+                HidError::OpenHidDeviceWithDeviceInfoError { device_info }
+            }
+            HidErrorRepr::IoError {
+                kind,
+                raw_os_error,
+                message,
+            } => HidError::IoError {
+                error: match raw_os_error {
+                    Some(code) => io::Error::from_raw_os_error(code),
+                    None => io::Error::new(kind.into(), message),
+                },
+            },
+            HidErrorRepr::InvalidReportDescriptor { offset, reason } => {
+                HidError::InvalidReportDescriptor {
+                    offset,
+                    reason: reason.into(),
+                }
+            }
+            HidErrorRepr::InvalidReport { message } => HidError::InvalidReport { message },
+            HidErrorRepr::TransactionTimedOut { attempts } => {
+                HidError::TransactionTimedOut { attempts }
+            }
+            HidErrorRepr::NotConnected => HidError::NotConnected,
+        }
+    }
+}
+
+impl Serialize for HidError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HidErrorRepr::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HidError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HidErrorRepr::deserialize(deserializer).map(HidError::from)
+    }
+}
@@ -0,0 +1,294 @@
+//! Reader and writer halves of a [`HidDevice`], see [`HidDevice::split()`].
+
+use std::io;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::{
+    get_input_report, io_write, read_until, send_feature_report, timeout_millis, write_report,
+    HidDevice, HidDeviceBackend, HidResult, PendingReports, ReadOutcome, SyncDeviceBackend,
+};
+
+/// Timeout of a single read, while a backend which can not be used from
+/// multiple threads at once is locked for reading.
+const READ_SLICE_MS: i32 = 10;
+
+enum Backend {
+    Concurrent(SyncDeviceBackend),
+    Serialized(Serialized),
+}
+
+/// A backend which can only be used by one thread at a time.
+///
+/// The lock is not fair, so the reader steps aside while writers are waiting.
+/// Otherwise it could take the lock again right after releasing it, over and
+/// over.
+struct Serialized {
+    backend: Mutex<Box<dyn HidDeviceBackend>>,
+    /// Number of writers waiting for or holding the backend.
+    writers: Mutex<usize>,
+    writers_done: Condvar,
+}
+
+impl Serialized {
+    fn new(backend: Box<dyn HidDeviceBackend>) -> Self {
+        Serialized {
+            backend: Mutex::new(backend),
+            writers: Mutex::new(0),
+            writers_done: Condvar::new(),
+        }
+    }
+
+    fn lock_for_write(&self) -> WriteGuard<'_> {
+        *self.writers.lock().unwrap() += 1;
+        WriteGuard {
+            backend: self.backend.lock().unwrap(),
+            serialized: self,
+        }
+    }
+
+    fn lock_for_read(&self) -> MutexGuard<'_, Box<dyn HidDeviceBackend>> {
+        loop {
+            let backend = self.backend.lock().unwrap();
+            let writers = self.writers.lock().unwrap();
+            if *writers == 0 {
+                return backend;
+            }
+
+            drop(backend);
+            drop(
+                self.writers_done
+                    .wait_while(writers, |writers| *writers > 0),
+            );
+        }
+    }
+}
+
+struct WriteGuard<'a> {
+    backend: MutexGuard<'a, Box<dyn HidDeviceBackend>>,
+    serialized: &'a Serialized,
+}
+
+impl Drop for WriteGuard<'_> {
+    fn drop(&mut self) {
+        let mut writers = self.serialized.writers.lock().unwrap();
+        *writers -= 1;
+        if *writers == 0 {
+            self.serialized.writers_done.notify_all();
+        }
+    }
+}
+
+struct Shared {
+    backend: Backend,
+    blocking: bool,
+    pending: PendingReports,
+}
+
+impl Shared {
+    fn with<T, F>(&self, f: F) -> T
+    where
+        F: FnOnce(&dyn HidDeviceBackend) -> T,
+    {
+        match self.backend {
+            Backend::Concurrent(ref backend) => f(&**backend),
+            Backend::Serialized(ref backend) => f(&**backend.lock_for_write().backend),
+        }
+    }
+
+    fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
+        if let Some(len) = self.pending.take(buf) {
+            return Ok(len);
+        }
+
+        let backend = match self.backend {
+            Backend::Concurrent(ref backend) => return backend.read_timeout(buf, timeout),
+            Backend::Serialized(ref backend) => backend,
+        };
+
+        // Read in short slices, so the writer gets the lock in between
+        let deadline = if timeout < 0 {
+            None
+        } else {
+            Some(Instant::now() + Duration::from_millis(timeout as u64))
+        };
+        loop {
+            let slice = match deadline {
+                None => READ_SLICE_MS,
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    timeout_millis(Some(remaining)).min(READ_SLICE_MS)
+                }
+            };
+
+            let len = backend.lock_for_read().read_timeout(buf, slice)?;
+            if len > 0 || deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Ok(len);
+            }
+        }
+    }
+}
+
+impl HidDevice {
+    /// Split the device into a reader and a writer, which can be used from
+    /// different threads.
+    ///
+    /// If the backend supports it, reading and writing happen at the same
+    /// time. This is the case for the `linux-native-hidraw` backend and the
+    /// [`mock`](crate::mock) backend. Otherwise the halves take turns: a
+    /// waiting read wakes up every 10 ms and lets pending writes go first, so
+    /// a write waits for about 10 ms at most.
+    ///
+    /// The blocking mode set with [`HidDevice::set_blocking_mode()`] and the
+    /// reports buffered by [`HidDevice::transact()`] are kept.
+    ///
+    /// ```
+    /// use hidapi::mock::{MockBackend, MockDevice};
+    /// use hidapi::HidApi;
+    /// use std::thread;
+    ///
+    /// let mock = MockDevice::new(0x1234, 0x5678);
+    /// // Reply to the command, once it was written
+    /// mock.expect_write_with_reply(&[0x00, 0x01], &[0x01, 0xAA]);
+    /// let backend = MockBackend::new();
+    /// backend.add_device(&mock);
+    /// let device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+    ///
+    /// let (reader, writer) = device.split();
+    /// let reply = thread::spawn(move || {
+    ///     let mut buf = [0u8; 64];
+    ///     let len = reader.read(&mut buf).unwrap();
+    ///     buf[..len].to_vec()
+    /// });
+    ///
+    /// writer.write(&[0x00, 0x01]).unwrap();
+    /// assert_eq!(reply.join().unwrap(), vec![0x01, 0xAA]);
+    /// ```
+    pub fn split(self) -> (HidReader, HidWriter) {
+        let backend = match self.inner.into_sync() {
+            Ok(backend) => Backend::Concurrent(backend),
+            Err(backend) => Backend::Serialized(Serialized::new(backend)),
+        };
+        let shared = Arc::new(Shared {
+            backend,
+            blocking: self.blocking.into_inner(),
+            pending: self.pending,
+        });
+
+        (
+            HidReader {
+                shared: shared.clone(),
+            },
+            HidWriter { shared },
+        )
+    }
+}
+
+/// Reading half of a [`HidDevice`], see [`HidDevice::split()`].
+///
+/// Implements [`io::Read`] like [`HidDevice`].
+pub struct HidReader {
+    shared: Arc<Shared>,
+}
+
+impl HidReader {
+    /// See [`HidDevice::read()`].
+    pub fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let timeout = if self.shared.blocking { -1 } else { 0 };
+        self.shared.read_timeout(buf, timeout)
+    }
+
+    /// See [`HidDevice::read_timeout()`].
+    pub fn read_timeout(&self, buf: &mut [u8], timeout: i32) -> HidResult<usize> {
+        self.shared.read_timeout(buf, timeout)
+    }
+
+    /// See [`HidDevice::read_with_timeout()`].
+    pub fn read_with_timeout(
+        &self,
+        buf: &mut [u8],
+        timeout: Option<Duration>,
+    ) -> HidResult<ReadOutcome> {
+        let len = self.shared.read_timeout(buf, timeout_millis(timeout))?;
+        Ok(ReadOutcome::from_len(len))
+    }
+
+    /// See [`HidDevice::read_until()`].
+    pub fn read_until(&self, buf: &mut [u8], deadline: Instant) -> HidResult<ReadOutcome> {
+        read_until(deadline, |timeout| self.read_with_timeout(buf, timeout))
+    }
+}
+
+impl io::Read for &HidReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            match HidReader::read(self, buf)? {
+                // Reading 0 bytes would signal the end of the stream
+                0 if self.shared.blocking => continue,
+                0 => return Err(io::ErrorKind::WouldBlock.into()),
+                len => return Ok(len),
+            }
+        }
+    }
+}
+
+impl io::Read for HidReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        io::Read::read(&mut &*self, buf)
+    }
+}
+
+/// Writing half of a [`HidDevice`], see [`HidDevice::split()`].
+///
+/// Implements [`io::Write`] like [`HidDevice`].
+pub struct HidWriter {
+    shared: Arc<Shared>,
+}
+
+impl HidWriter {
+    /// See [`HidDevice::write()`].
+    pub fn write(&self, data: &[u8]) -> HidResult<usize> {
+        self.shared.with(|backend| write_report(backend, data))
+    }
+
+    /// See [`HidDevice::send_feature_report()`].
+    pub fn send_feature_report(&self, data: &[u8]) -> HidResult<()> {
+        self.shared
+            .with(|backend| send_feature_report(backend, data))
+    }
+
+    /// See [`HidDevice::get_feature_report()`].
+    pub fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        self.shared.with(|backend| backend.get_feature_report(buf))
+    }
+
+    /// See [`HidDevice::get_input_report()`].
+    pub fn get_input_report(&self, report_id: u8, buf: &mut [u8]) -> HidResult<usize> {
+        self.shared
+            .with(|backend| get_input_report(backend, report_id, buf))
+    }
+}
+
+impl io::Write for &HidWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io_write(buf, |buf| HidWriter::write(self, buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl io::Write for HidWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::Write::write(&mut &*self, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
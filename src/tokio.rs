@@ -0,0 +1,226 @@
+//! Async access to a [`HidDevice`] for the tokio runtime.
+//!
+//! [`AsyncHidDevice`] waits for input reports without blocking a runtime
+//! thread. With the `linux-native-hidraw` backend it waits for the hidraw file
+//! descriptor to become readable. The other backends offer no pollable handle,
+//! so a reader thread like the one of [`HidDevice::spawn_reader()`] hands the
+//! reports over.
+//!
+//! ```
+//! use futures_core::Stream;
+//! use hidapi::mock::{MockBackend, MockDevice};
+//! use hidapi::tokio::AsyncHidDevice;
+//! use hidapi::HidApi;
+//! use std::future::poll_fn;
+//! use std::pin::Pin;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mock = MockDevice::new(0x1234, 0x5678);
+//! let backend = MockBackend::new();
+//! backend.add_device(&mock);
+//!
+//! let api = HidApi::with_backend(backend).unwrap();
+//! let device = AsyncHidDevice::new(api.open(0x1234, 0x5678).unwrap()).unwrap();
+//!
+//! mock.push_input_report(&[0x01, 0x02]);
+//! let mut buf = [0u8; 64];
+//! let len = device.read(&mut buf).await.unwrap();
+//! assert_eq!(&buf[..len], &[0x01, 0x02]);
+//!
+//! mock.push_input_report(&[0x03]);
+//! let mut reports = device.input_reports();
+//! let report = poll_fn(|cx| Pin::new(&mut reports).poll_next(cx)).await;
+//! assert_eq!(report.unwrap().unwrap(), vec![0x03]);
+//! # }
+//! ```
+
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+#[cfg(unix)]
+use tokio::io::unix::AsyncFd;
+
+use crate::reader::ReportThread;
+use crate::{HidDevice, HidError, HidReader, HidResult, HidWriter, ReaderConfig, MAX_REPORT_LEN};
+
+enum Reader {
+    #[cfg(unix)]
+    Fd {
+        // Deregistered before `input` and the writer release the device,
+        // which closes the file descriptor
+        fd: AsyncFd<std::os::unix::io::RawFd>,
+        input: HidReader,
+    },
+    Thread(ReportThread),
+}
+
+struct Shared {
+    reader: Reader,
+    // Writes use their own half, so they do not wait for a poll to finish
+    output: HidWriter,
+}
+
+impl Shared {
+    fn poll_read(&self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<HidResult<usize>> {
+        match self.reader {
+            #[cfg(unix)]
+            Reader::Fd { ref fd, ref input } => loop {
+                // Reports buffered by `HidDevice::transact()` do not make the
+                // fd readable, so try to read before waiting
+                match input.read_timeout(buf, 0) {
+                    Ok(0) => {}
+                    res => return Poll::Ready(res),
+                }
+                ready!(fd.poll_read_ready(cx))?.clear_ready();
+            },
+            Reader::Thread(ref reports) => Poll::Ready(match ready!(reports.poll_next(cx)) {
+                Some(Ok(report)) => {
+                    let len = report.data.len().min(buf.len());
+                    buf[..len].copy_from_slice(&report.data[..len]);
+                    Ok(len)
+                }
+                Some(Err(err)) => Err(err),
+                None => Err(HidError::HidApiError {
+                    message: "reader thread has stopped".into(),
+                }),
+            }),
+        }
+    }
+}
+
+/// A [`HidDevice`] with async I/O methods.
+///
+/// Reads are cancellation safe: a report is only taken from the device when
+/// the future completes. Only one task should read at a time, either with
+/// [`AsyncHidDevice::read()`] or from the [`InputReports`] stream, because
+/// only the last task polling is woken up.
+///
+/// All other operations run on tokio's blocking thread pool. If their future
+/// is dropped, the operation still runs to completion in the background. With
+/// a backend, which can not read and write at the same time, they may have to
+/// wait for up to 10 ms, see [`HidDevice::split()`].
+pub struct AsyncHidDevice {
+    shared: Arc<Shared>,
+}
+
+impl AsyncHidDevice {
+    /// Wrap a device for async use.
+    ///
+    /// With a backend that offers a pollable file descriptor, this must be
+    /// called from within a tokio runtime with I/O enabled, otherwise an error
+    /// is returned. Other backends start a reader thread, see
+    /// [`HidDevice::spawn_reader()`], which reads reports until the
+    /// `AsyncHidDevice` is dropped or reading fails.
+    pub fn new(device: HidDevice) -> HidResult<Self> {
+        #[cfg(unix)]
+        {
+            if let Some(fd) = device.inner.pollable_fd() {
+                // `AsyncFd::new()` panics without a runtime
+                if tokio::runtime::Handle::try_current().is_err() {
+                    return Err(io::Error::other(
+                        "AsyncHidDevice::new() must be called from within a tokio runtime",
+                    )
+                    .into());
+                }
+
+                let fd = AsyncFd::new(fd)?;
+                let (input, output) = device.split();
+                return Ok(AsyncHidDevice::with_reader(
+                    Reader::Fd { fd, input },
+                    output,
+                ));
+            }
+        }
+
+        let (input, output) = device.split();
+        let reports = ReportThread::spawn(input, ReaderConfig::new())?;
+        Ok(AsyncHidDevice::with_reader(Reader::Thread(reports), output))
+    }
+
+    fn with_reader(reader: Reader, output: HidWriter) -> Self {
+        AsyncHidDevice {
+            shared: Arc::new(Shared { reader, output }),
+        }
+    }
+
+    /// Read an input report, see [`HidDevice::read()`].
+    pub async fn read(&self, buf: &mut [u8]) -> HidResult<usize> {
+        std::future::poll_fn(|cx| self.shared.poll_read(cx, buf)).await
+    }
+
+    /// Write an output report, see [`HidDevice::write()`].
+    pub async fn write(&self, data: &[u8]) -> HidResult<usize> {
+        let data = data.to_vec();
+        self.blocking(move |device| device.write(&data)).await
+    }
+
+    /// Send a feature report, see [`HidDevice::send_feature_report()`].
+    pub async fn send_feature_report(&self, data: &[u8]) -> HidResult<()> {
+        let data = data.to_vec();
+        self.blocking(move |device| device.send_feature_report(&data))
+            .await
+    }
+
+    /// Get a feature report, see [`HidDevice::get_feature_report()`].
+    pub async fn get_feature_report(&self, buf: &mut [u8]) -> HidResult<usize> {
+        let mut report = buf.to_vec();
+        let (report, len) = self
+            .blocking(move |device| {
+                let len = device.get_feature_report(&mut report)?;
+                Ok((report, len))
+            })
+            .await?;
+        buf[..len].copy_from_slice(&report[..len]);
+        Ok(len)
+    }
+
+    /// A stream of input reports, which ends after the first error.
+    pub fn input_reports(&self) -> InputReports {
+        InputReports {
+            shared: self.shared.clone(),
+            buf: vec![0u8; MAX_REPORT_LEN],
+            done: false,
+        }
+    }
+
+    async fn blocking<T, F>(&self, f: F) -> HidResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&HidWriter) -> HidResult<T> + Send + 'static,
+    {
+        let shared = self.shared.clone();
+        match tokio::task::spawn_blocking(move || f(&shared.output)).await {
+            Ok(res) => res,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(err) => Err(io::Error::other(err).into()),
+        }
+    }
+}
+
+/// Stream of the input reports of an [`AsyncHidDevice`].
+///
+/// Every item is one report as returned by [`HidDevice::read()`].
+pub struct InputReports {
+    shared: Arc<Shared>,
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl Stream for InputReports {
+    type Item = HidResult<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let res = ready!(this.shared.poll_read(cx, &mut this.buf));
+        this.done = res.is_err();
+        Poll::Ready(Some(res.map(|len| this.buf[..len].to_vec())))
+    }
+}
@@ -0,0 +1,144 @@
+//! Request/response helper, see [`HidDevice::transact()`].
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{timeout_millis, HidDevice, HidError, HidResult, MAX_REPORT_LEN};
+
+/// How often [`HidDevice::transact_with_retry()`] repeats a request, which got
+/// no reply in time.
+///
+/// The delay before a retry starts at the initial backoff and doubles with
+/// every further retry, up to the maximum backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(0)
+    }
+}
+
+impl RetryPolicy {
+    /// Retry up to `retries` times, with an initial backoff of 10 ms and a
+    /// maximum backoff of 1 s.
+    pub fn new(retries: u32) -> Self {
+        RetryPolicy {
+            retries,
+            backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+}
+
+impl HidDevice {
+    /// Write `request` and wait up to `timeout` for the input report, for
+    /// which `matcher` returns `true`.
+    ///
+    /// Other reports, which arrive in the meantime, are buffered and returned
+    /// by the following reads, so they are not lost for other readers. At most
+    /// 64 reports are buffered, after that the oldest ones are dropped.
+    ///
+    /// Fails with [`HidError::TransactionTimedOut`] if no matching report
+    /// arrived in time. See [`HidDevice::transact_with_retry()`] to repeat the
+    /// request in that case.
+    ///
+    /// ```
+    /// use hidapi::mock::{MockBackend, MockDevice};
+    /// use hidapi::HidApi;
+    /// use std::time::Duration;
+    ///
+    /// let mock = MockDevice::new(0x1234, 0x5678);
+    /// mock.expect_write_with_reply(&[0x11, 0x01], &[0x11, 0x01, 0xAA]);
+    /// let backend = MockBackend::new();
+    /// backend.add_device(&mock);
+    /// let device = HidApi::with_backend(backend).unwrap().open(0x1234, 0x5678).unwrap();
+    ///
+    /// // An unrelated report, e.g. a key press, arrives before the reply
+    /// mock.push_input_report(&[0x01, 0x04]);
+    /// let is_reply = |report: &[u8]| report.starts_with(&[0x11, 0x01]);
+    /// let reply = device
+    ///     .transact(&[0x11, 0x01], is_reply, Duration::from_millis(100))
+    ///     .unwrap();
+    /// assert_eq!(reply, vec![0x11, 0x01, 0xAA]);
+    ///
+    /// // The unrelated report is still available
+    /// let mut buf = [0u8; 64];
+    /// assert_eq!(device.read(&mut buf).unwrap(), 2);
+    /// assert_eq!(&buf[..2], &[0x01, 0x04]);
+    /// ```
+    pub fn transact<F>(&self, request: &[u8], matcher: F, timeout: Duration) -> HidResult<Vec<u8>>
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        self.transact_with_retry(request, matcher, timeout, RetryPolicy::default())
+    }
+
+    /// Like [`HidDevice::transact()`], but write `request` again, if no
+    /// matching report arrived within `timeout`.
+    ///
+    /// Every attempt waits up to `timeout`. A reply to an earlier attempt,
+    /// which arrives late, is accepted as well. Errors other than the timeout
+    /// are returned right away.
+    pub fn transact_with_retry<F>(
+        &self,
+        request: &[u8],
+        mut matcher: F,
+        timeout: Duration,
+        retry: RetryPolicy,
+    ) -> HidResult<Vec<u8>>
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        let mut buf = vec![0u8; MAX_REPORT_LEN];
+        let mut backoff = retry.backoff;
+
+        for attempt in 0..=retry.retries {
+            if attempt > 0 {
+                thread::sleep(backoff);
+                backoff = backoff.saturating_mul(2).min(retry.max_backoff);
+            }
+
+            self.write(request)?;
+            // A timeout too large for an `Instant` waits forever
+            let deadline = Instant::now().checked_add(timeout);
+            loop {
+                let remaining =
+                    deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+                // Read from the backend, reports buffered before were not
+                // sent in reply to this request
+                let len = self
+                    .inner
+                    .read_timeout(&mut buf, timeout_millis(remaining))?;
+                if len > 0 {
+                    let report = &buf[..len];
+                    if matcher(report) {
+                        return Ok(report.to_vec());
+                    }
+                    self.pending.push(report.to_vec());
+                }
+                if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    break;
+                }
+            }
+        }
+
+        Err(HidError::TransactionTimedOut {
+            attempts: retry.retries.saturating_add(1),
+        })
+    }
+}
@@ -0,0 +1,241 @@
+//! Names of the usage pages and usages defined by the HID Usage Tables.
+//!
+//! The tables were transcribed by hand from the HID Usage Tables for USB,
+//! version 1.4, published by the USB Implementers Forum. Pages, whose usages
+//! are not names, like Unicode, are not included.
+//!
+//! The tables are static data and none of the lookups allocate, so this
+//! module does not depend on anything beyond `core`.
+//!
+//! ```
+//! use hidapi::descriptor::Usage;
+//! use hidapi::usage_tables;
+//!
+//! assert_eq!(
+//!     usage_tables::describe(0x01, 0x02).to_string(),
+//!     "Generic Desktop / Mouse"
+//! );
+//! assert_eq!(usage_tables::describe(0x09, 3).to_string(), "Button / Button 3");
+//!
+//! let page = usage_tables::page_by_name("generic desktop").unwrap();
+//! assert_eq!(page.id(), 0x01);
+//! assert_eq!(page.usage_id("Joystick"), Some(0x04));
+//!
+//! assert_eq!(
+//!     usage_tables::find("Consumer", "Volume Increment"),
+//!     Some(Usage::new(0x0C, 0xE9))
+//! );
+//! ```
+
+use core::fmt;
+
+use crate::descriptor::Usage;
+
+/// Builds the table of all usage pages.
+///
+/// Pages either list their usages with `named[id => "name", ...]` or, if the
+/// usage IDs are plain numbers, give the prefix of the name with
+/// `numbered("prefix")`.
+macro_rules! usage_pages {
+    ($($page:expr, $name:expr => $kind:ident $usages:tt;)*) => {
+        pub(super) static PAGES: &[UsagePage] = &[
+            $(UsagePage {
+                id: $page,
+                name: $name,
+                usages: usages!($kind $usages),
+            },)*
+        ];
+    };
+}
+
+macro_rules! usages {
+    (named [$($id:expr => $name:expr,)*]) => {
+        Usages::Named(&[$(($id, $name),)*])
+    };
+    (numbered ($prefix:expr)) => {
+        Usages::Numbered($prefix)
+    };
+}
+
+mod pages;
+
+/// First usage page reserved for vendor defined usages.
+pub const VENDOR_DEFINED_START: u16 = 0xFF00;
+
+enum Usages {
+    Named(&'static [(u16, &'static str)]),
+    Numbered(&'static str),
+}
+
+/// A usage page from the HID Usage Tables.
+pub struct UsagePage {
+    id: u16,
+    name: &'static str,
+    usages: Usages,
+}
+
+impl UsagePage {
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The named usages of this page, sorted by ID.
+    ///
+    /// Empty for pages like Button, where the usage ID is just a number.
+    pub fn usages(&self) -> &'static [(u16, &'static str)] {
+        match self.usages {
+            Usages::Named(usages) => usages,
+            Usages::Numbered(_) => &[],
+        }
+    }
+
+    /// Look up the name of a usage of this page.
+    pub fn usage_name(&self, id: u16) -> Option<UsageName> {
+        match self.usages {
+            Usages::Named(usages) => usages
+                .binary_search_by_key(&id, |&(id, _)| id)
+                .ok()
+                .map(|index| UsageName::Named(usages[index].1)),
+            Usages::Numbered(_) if id == 0 => None,
+            Usages::Numbered(prefix) => Some(UsageName::Numbered(prefix, id)),
+        }
+    }
+
+    /// Look up a usage ID by its name, ignoring ASCII case.
+    pub fn usage_id(&self, name: &str) -> Option<u16> {
+        match self.usages {
+            Usages::Named(usages) => usages
+                .iter()
+                .find(|&&(_, n)| n.eq_ignore_ascii_case(name))
+                .map(|&(id, _)| id),
+            Usages::Numbered(prefix) => {
+                let (head, number) = name.split_at(name.find(' ')?);
+                if !head.eq_ignore_ascii_case(prefix) {
+                    return None;
+                }
+                number[1..].parse().ok().filter(|&id| id != 0)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for UsagePage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UsagePage")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// The name of a usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageName {
+    /// A usage listed by name in the tables.
+    Named(&'static str),
+    /// A usage of a page like Button, which is named by a prefix and its ID.
+    Numbered(&'static str, u16),
+}
+
+impl fmt::Display for UsageName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UsageName::Named(name) => f.write_str(name),
+            UsageName::Numbered(prefix, id) => write!(f, "{} {}", prefix, id),
+        }
+    }
+}
+
+/// All known usage pages, sorted by ID.
+///
+/// The number of named usages of every page, as listed by the HID Usage
+/// Tables 1.4:
+///
+/// ```
+/// let counts: Vec<_> = hidapi::usage_tables::pages()
+///     .iter()
+///     .map(|page| (page.id(), page.usages().len()))
+///     .collect();
+/// assert_eq!(
+///     counts,
+///     [
+///         (0x0001, 114), (0x0002, 51), (0x0003, 12), (0x0004, 34), (0x0005, 29),
+///         (0x0006, 24), (0x0007, 218), (0x0008, 96), (0x0009, 0), (0x000A, 0),
+///         (0x000B, 89), (0x000C, 429), (0x000D, 108), (0x000E, 29), (0x000F, 105),
+///         (0x0011, 10), (0x0012, 34), (0x0014, 69), (0x0020, 623), (0x0040, 29),
+///         (0x0041, 43), (0x0059, 33), (0x0080, 4), (0x0081, 0), (0x0082, 45),
+///         (0x0084, 77), (0x0085, 91), (0x008C, 194), (0x008D, 37), (0x008E, 10),
+///         (0x0090, 2), (0x0091, 27), (0xF1D0, 3),
+///     ]
+/// );
+/// ```
+pub fn pages() -> &'static [UsagePage] {
+    pages::PAGES
+}
+
+/// Look up a usage page by its ID.
+pub fn page(id: u16) -> Option<&'static UsagePage> {
+    pages::PAGES
+        .binary_search_by_key(&id, |page| page.id)
+        .ok()
+        .map(|index| &pages::PAGES[index])
+}
+
+/// Look up a usage page by its name, ignoring ASCII case.
+pub fn page_by_name(name: &str) -> Option<&'static UsagePage> {
+    pages::PAGES
+        .iter()
+        .find(|page| page.name.eq_ignore_ascii_case(name))
+}
+
+/// Look up the name of a usage.
+pub fn usage_name(page_id: u16, usage_id: u16) -> Option<UsageName> {
+    page(page_id)?.usage_name(usage_id)
+}
+
+/// Look up a usage by the name of its page and its own name, ignoring ASCII case.
+pub fn find(page_name: &str, usage_name: &str) -> Option<Usage> {
+    let page = page_by_name(page_name)?;
+    Some(Usage::new(page.id, page.usage_id(usage_name)?))
+}
+
+pub fn is_vendor_defined(page_id: u16) -> bool {
+    page_id >= VENDOR_DEFINED_START
+}
+
+/// Describe a usage for humans, see [`Description`].
+pub fn describe(page_id: u16, usage_id: u16) -> Description {
+    Description { page_id, usage_id }
+}
+
+/// Displays a usage as `"<page> / <usage>"`, e.g. `"Generic Desktop / Mouse"`.
+///
+/// Unknown pages and usages are printed as hexadecimal numbers, vendor defined
+/// pages as `"Vendor Defined 0xFF00"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Description {
+    page_id: u16,
+    usage_id: u16,
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let page = page(self.page_id);
+        match page {
+            Some(page) => f.write_str(page.name)?,
+            None if is_vendor_defined(self.page_id) => {
+                write!(f, "Vendor Defined {:#06X}", self.page_id)?
+            }
+            None => write!(f, "{:#06X}", self.page_id)?,
+        }
+
+        match page.and_then(|page| page.usage_name(self.usage_id)) {
+            Some(name) => write!(f, " / {}", name),
+            None => write!(f, " / {:#06X}", self.usage_id),
+        }
+    }
+}
@@ -0,0 +1,2744 @@
+//! The usage pages and usages defined by the HID Usage Tables 1.4, see the
+//! [module documentation](super).
+//!
+//! Every page lists its usages sorted by ID. Pages whose usages are plain
+//! numbers, like buttons, only specify the prefix used for the name.
+
+use super::{UsagePage, Usages};
+
+usage_pages! {
+    0x0001, "Generic Desktop" => named[
+        0x0001 => "Pointer",
+        0x0002 => "Mouse",
+        0x0004 => "Joystick",
+        0x0005 => "Gamepad",
+        0x0006 => "Keyboard",
+        0x0007 => "Keypad",
+        0x0008 => "Multi-axis Controller",
+        0x0009 => "Tablet PC System Controls",
+        0x000A => "Water Cooling Device",
+        0x000B => "Computer Chassis Device",
+        0x000C => "Wireless Radio Controls",
+        0x000D => "Portable Device Control",
+        0x000E => "System Multi-Axis Controller",
+        0x000F => "Spatial Controller",
+        0x0010 => "Assistive Control",
+        0x0011 => "Device Dock",
+        0x0012 => "Dockable Device",
+        0x0013 => "Call State Management Control",
+        0x0030 => "X",
+        0x0031 => "Y",
+        0x0032 => "Z",
+        0x0033 => "Rx",
+        0x0034 => "Ry",
+        0x0035 => "Rz",
+        0x0036 => "Slider",
+        0x0037 => "Dial",
+        0x0038 => "Wheel",
+        0x0039 => "Hat Switch",
+        0x003A => "Counted Buffer",
+        0x003B => "Byte Count",
+        0x003C => "Motion Wakeup",
+        0x003D => "Start",
+        0x003E => "Select",
+        0x0040 => "Vx",
+        0x0041 => "Vy",
+        0x0042 => "Vz",
+        0x0043 => "Vbrx",
+        0x0044 => "Vbry",
+        0x0045 => "Vbrz",
+        0x0046 => "Vno",
+        0x0047 => "Feature Notification",
+        0x0048 => "Resolution Multiplier",
+        0x0049 => "Qx",
+        0x004A => "Qy",
+        0x004B => "Qz",
+        0x004C => "Qw",
+        0x0080 => "System Control",
+        0x0081 => "System Power Down",
+        0x0082 => "System Sleep",
+        0x0083 => "System Wake Up",
+        0x0084 => "System Context Menu",
+        0x0085 => "System Main Menu",
+        0x0086 => "System App Menu",
+        0x0087 => "System Menu Help",
+        0x0088 => "System Menu Exit",
+        0x0089 => "System Menu Select",
+        0x008A => "System Menu Right",
+        0x008B => "System Menu Left",
+        0x008C => "System Menu Up",
+        0x008D => "System Menu Down",
+        0x008E => "System Cold Restart",
+        0x008F => "System Warm Restart",
+        0x0090 => "D-pad Up",
+        0x0091 => "D-pad Down",
+        0x0092 => "D-pad Right",
+        0x0093 => "D-pad Left",
+        0x0094 => "Index Trigger",
+        0x0095 => "Palm Trigger",
+        0x0096 => "Thumbstick",
+        0x0097 => "System Function Shift",
+        0x0098 => "System Function Shift Lock",
+        0x0099 => "System Function Shift Lock Indicator",
+        0x009A => "System Dismiss Notification",
+        0x009B => "System Do Not Disturb",
+        0x00A0 => "System Dock",
+        0x00A1 => "System Undock",
+        0x00A2 => "System Setup",
+        0x00A3 => "System Break",
+        0x00A4 => "System Debugger Break",
+        0x00A5 => "Application Break",
+        0x00A6 => "Application Debugger Break",
+        0x00A7 => "System Speaker Mute",
+        0x00A8 => "System Hibernate",
+        0x00A9 => "System Microphone Mute",
+        0x00B0 => "System Display Invert",
+        0x00B1 => "System Display Internal",
+        0x00B2 => "System Display External",
+        0x00B3 => "System Display Both",
+        0x00B4 => "System Display Dual",
+        0x00B5 => "System Display Toggle Int/Ext Mode",
+        0x00B6 => "System Display Swap Primary/Secondary",
+        0x00B7 => "System Display Toggle LCD Autoscale",
+        0x00C0 => "Sensor Zone",
+        0x00C1 => "RPM",
+        0x00C2 => "Coolant Level",
+        0x00C3 => "Coolant Critical Level",
+        0x00C4 => "Coolant Pump",
+        0x00C5 => "Chassis Enclosure",
+        0x00C6 => "Wireless Radio Button",
+        0x00C7 => "Wireless Radio LED",
+        0x00C8 => "Wireless Radio Slider Switch",
+        0x00C9 => "System Display Rotation Lock Button",
+        0x00CA => "System Display Rotation Lock Slider Switch",
+        0x00CB => "Control Enable",
+        0x00D0 => "Dockable Device Unique ID",
+        0x00D1 => "Dockable Device Vendor ID",
+        0x00D2 => "Dockable Device Primary Usage Page",
+        0x00D3 => "Dockable Device Primary Usage ID",
+        0x00D4 => "Dockable Device Docking State",
+        0x00D5 => "Dockable Device Display Occlusion",
+        0x00D6 => "Dockable Device Object Type",
+        0x00E0 => "Call Active LED",
+        0x00E1 => "Call Mute Toggle",
+        0x00E2 => "Call Mute LED",
+    ];
+    0x0002, "Simulation Controls" => named[
+        0x0001 => "Flight Simulation Device",
+        0x0002 => "Automobile Simulation Device",
+        0x0003 => "Tank Simulation Device",
+        0x0004 => "Spaceship Simulation Device",
+        0x0005 => "Submarine Simulation Device",
+        0x0006 => "Sailing Simulation Device",
+        0x0007 => "Motorcycle Simulation Device",
+        0x0008 => "Sports Simulation Device",
+        0x0009 => "Airplane Simulation Device",
+        0x000A => "Helicopter Simulation Device",
+        0x000B => "Magic Carpet Simulation Device",
+        0x000C => "Bicycle Simulation Device",
+        0x0020 => "Flight Control Stick",
+        0x0021 => "Flight Stick",
+        0x0022 => "Cyclic Control",
+        0x0023 => "Cyclic Trim",
+        0x0024 => "Flight Yoke",
+        0x0025 => "Track Control",
+        0x00B0 => "Aileron",
+        0x00B1 => "Aileron Trim",
+        0x00B2 => "Anti-Torque Control",
+        0x00B3 => "Autopilot Enable",
+        0x00B4 => "Chaff Release",
+        0x00B5 => "Collective Control",
+        0x00B6 => "Dive Brake",
+        0x00B7 => "Electronic Countermeasures",
+        0x00B8 => "Elevator",
+        0x00B9 => "Elevator Trim",
+        0x00BA => "Rudder",
+        0x00BB => "Throttle",
+        0x00BC => "Flight Communications",
+        0x00BD => "Flare Release",
+        0x00BE => "Landing Gear",
+        0x00BF => "Toe Brake",
+        0x00C0 => "Trigger",
+        0x00C1 => "Weapons Arm",
+        0x00C2 => "Weapons Select",
+        0x00C3 => "Wing Flaps",
+        0x00C4 => "Accelerator",
+        0x00C5 => "Brake",
+        0x00C6 => "Clutch",
+        0x00C7 => "Shifter",
+        0x00C8 => "Steering",
+        0x00C9 => "Turret Direction",
+        0x00CA => "Barrel Elevation",
+        0x00CB => "Dive Plane",
+        0x00CC => "Ballast",
+        0x00CD => "Bicycle Crank",
+        0x00CE => "Handle Bars",
+        0x00CF => "Front Brake",
+        0x00D0 => "Rear Brake",
+    ];
+    0x0003, "VR Controls" => named[
+        0x0001 => "Belt",
+        0x0002 => "Body Suit",
+        0x0003 => "Flexor",
+        0x0004 => "Glove",
+        0x0005 => "Head Tracker",
+        0x0006 => "Head Mounted Display",
+        0x0007 => "Hand Tracker",
+        0x0008 => "Oculometer",
+        0x0009 => "Vest",
+        0x000A => "Animatronic Device",
+        0x0020 => "Stereo Enable",
+        0x0021 => "Display Enable",
+    ];
+    0x0004, "Sport Controls" => named[
+        0x0001 => "Baseball Bat",
+        0x0002 => "Golf Club",
+        0x0003 => "Rowing Machine",
+        0x0004 => "Treadmill",
+        0x0030 => "Oar",
+        0x0031 => "Slope",
+        0x0032 => "Rate",
+        0x0033 => "Stick Speed",
+        0x0034 => "Stick Face Angle",
+        0x0035 => "Stick Heel/Toe",
+        0x0036 => "Stick Follow Through",
+        0x0037 => "Stick Tempo",
+        0x0038 => "Stick Type",
+        0x0039 => "Stick Height",
+        0x0050 => "Putter",
+        0x0051 => "1 Iron",
+        0x0052 => "2 Iron",
+        0x0053 => "3 Iron",
+        0x0054 => "4 Iron",
+        0x0055 => "5 Iron",
+        0x0056 => "6 Iron",
+        0x0057 => "7 Iron",
+        0x0058 => "8 Iron",
+        0x0059 => "9 Iron",
+        0x005A => "10 Iron",
+        0x005B => "11 Iron",
+        0x005C => "Sand Wedge",
+        0x005D => "Loft Wedge",
+        0x005E => "Power Wedge",
+        0x005F => "1 Wood",
+        0x0060 => "3 Wood",
+        0x0061 => "5 Wood",
+        0x0062 => "7 Wood",
+        0x0063 => "9 Wood",
+    ];
+    0x0005, "Game Controls" => named[
+        0x0001 => "3D Game Controller",
+        0x0002 => "Pinball Device",
+        0x0003 => "Gun Device",
+        0x0020 => "Point of View",
+        0x0021 => "Turn Right/Left",
+        0x0022 => "Pitch Forward/Backward",
+        0x0023 => "Roll Right/Left",
+        0x0024 => "Move Right/Left",
+        0x0025 => "Move Forward/Backward",
+        0x0026 => "Move Up/Down",
+        0x0027 => "Lean Right/Left",
+        0x0028 => "Lean Forward/Backward",
+        0x0029 => "Height of POV",
+        0x002A => "Flipper",
+        0x002B => "Secondary Flipper",
+        0x002C => "Bump",
+        0x002D => "New Game",
+        0x002E => "Shoot Ball",
+        0x002F => "Player",
+        0x0030 => "Gun Bolt",
+        0x0031 => "Gun Clip",
+        0x0032 => "Gun Selector",
+        0x0033 => "Gun Single Shot",
+        0x0034 => "Gun Burst",
+        0x0035 => "Gun Automatic",
+        0x0036 => "Gun Safety",
+        0x0037 => "Gamepad Fire/Jump",
+        0x0039 => "Gamepad Trigger",
+        0x003A => "Form-fitting Gamepad",
+    ];
+    0x0006, "Generic Device Controls" => named[
+        0x0001 => "Background/Nonuser Controls",
+        0x0020 => "Battery Strength",
+        0x0021 => "Wireless Channel",
+        0x0022 => "Wireless ID",
+        0x0023 => "Discover Wireless Control",
+        0x0024 => "Security Code Character Entered",
+        0x0025 => "Security Code Character Erased",
+        0x0026 => "Security Code Cleared",
+        0x0027 => "Sequence ID",
+        0x0028 => "Sequence ID Reset",
+        0x0029 => "RF Signal Strength",
+        0x002A => "Software Version",
+        0x002B => "Protocol Version",
+        0x002C => "Hardware Version",
+        0x002D => "Major",
+        0x002E => "Minor",
+        0x002F => "Revision",
+        0x0030 => "Handedness",
+        0x0031 => "Either Hand",
+        0x0032 => "Left Hand",
+        0x0033 => "Right Hand",
+        0x0034 => "Both Hands",
+        0x0040 => "Grip Pose Offset",
+        0x0041 => "Pointer Pose Offset",
+    ];
+    0x0007, "Keyboard/Keypad" => named[
+        0x0001 => "ErrorRollOver",
+        0x0002 => "POSTFail",
+        0x0003 => "ErrorUndefined",
+        0x0004 => "Keyboard a and A",
+        0x0005 => "Keyboard b and B",
+        0x0006 => "Keyboard c and C",
+        0x0007 => "Keyboard d and D",
+        0x0008 => "Keyboard e and E",
+        0x0009 => "Keyboard f and F",
+        0x000A => "Keyboard g and G",
+        0x000B => "Keyboard h and H",
+        0x000C => "Keyboard i and I",
+        0x000D => "Keyboard j and J",
+        0x000E => "Keyboard k and K",
+        0x000F => "Keyboard l and L",
+        0x0010 => "Keyboard m and M",
+        0x0011 => "Keyboard n and N",
+        0x0012 => "Keyboard o and O",
+        0x0013 => "Keyboard p and P",
+        0x0014 => "Keyboard q and Q",
+        0x0015 => "Keyboard r and R",
+        0x0016 => "Keyboard s and S",
+        0x0017 => "Keyboard t and T",
+        0x0018 => "Keyboard u and U",
+        0x0019 => "Keyboard v and V",
+        0x001A => "Keyboard w and W",
+        0x001B => "Keyboard x and X",
+        0x001C => "Keyboard y and Y",
+        0x001D => "Keyboard z and Z",
+        0x001E => "Keyboard 1 and !",
+        0x001F => "Keyboard 2 and @",
+        0x0020 => "Keyboard 3 and #",
+        0x0021 => "Keyboard 4 and $",
+        0x0022 => "Keyboard 5 and %",
+        0x0023 => "Keyboard 6 and ^",
+        0x0024 => "Keyboard 7 and &",
+        0x0025 => "Keyboard 8 and *",
+        0x0026 => "Keyboard 9 and (",
+        0x0027 => "Keyboard 0 and )",
+        0x0028 => "Keyboard Return (ENTER)",
+        0x0029 => "Keyboard ESCAPE",
+        0x002A => "Keyboard DELETE (Backspace)",
+        0x002B => "Keyboard Tab",
+        0x002C => "Keyboard Spacebar",
+        0x002D => "Keyboard - and (underscore)",
+        0x002E => "Keyboard = and +",
+        0x002F => "Keyboard [ and {",
+        0x0030 => "Keyboard ] and }",
+        0x0031 => "Keyboard \\ and |",
+        0x0032 => "Keyboard Non-US # and ~",
+        0x0033 => "Keyboard ; and :",
+        0x0034 => "Keyboard ' and \"",
+        0x0035 => "Keyboard Grave Accent and Tilde",
+        0x0036 => "Keyboard , and <",
+        0x0037 => "Keyboard . and >",
+        0x0038 => "Keyboard / and ?",
+        0x0039 => "Keyboard Caps Lock",
+        0x003A => "Keyboard F1",
+        0x003B => "Keyboard F2",
+        0x003C => "Keyboard F3",
+        0x003D => "Keyboard F4",
+        0x003E => "Keyboard F5",
+        0x003F => "Keyboard F6",
+        0x0040 => "Keyboard F7",
+        0x0041 => "Keyboard F8",
+        0x0042 => "Keyboard F9",
+        0x0043 => "Keyboard F10",
+        0x0044 => "Keyboard F11",
+        0x0045 => "Keyboard F12",
+        0x0046 => "Keyboard PrintScreen",
+        0x0047 => "Keyboard Scroll Lock",
+        0x0048 => "Keyboard Pause",
+        0x0049 => "Keyboard Insert",
+        0x004A => "Keyboard Home",
+        0x004B => "Keyboard PageUp",
+        0x004C => "Keyboard Delete Forward",
+        0x004D => "Keyboard End",
+        0x004E => "Keyboard PageDown",
+        0x004F => "Keyboard RightArrow",
+        0x0050 => "Keyboard LeftArrow",
+        0x0051 => "Keyboard DownArrow",
+        0x0052 => "Keyboard UpArrow",
+        0x0053 => "Keypad Num Lock and Clear",
+        0x0054 => "Keypad /",
+        0x0055 => "Keypad *",
+        0x0056 => "Keypad -",
+        0x0057 => "Keypad +",
+        0x0058 => "Keypad ENTER",
+        0x0059 => "Keypad 1 and End",
+        0x005A => "Keypad 2 and Down Arrow",
+        0x005B => "Keypad 3 and PageDn",
+        0x005C => "Keypad 4 and Left Arrow",
+        0x005D => "Keypad 5",
+        0x005E => "Keypad 6 and Right Arrow",
+        0x005F => "Keypad 7 and Home",
+        0x0060 => "Keypad 8 and Up Arrow",
+        0x0061 => "Keypad 9 and PageUp",
+        0x0062 => "Keypad 0 and Insert",
+        0x0063 => "Keypad . and Delete",
+        0x0064 => "Keyboard Non-US \\ and |",
+        0x0065 => "Keyboard Application",
+        0x0066 => "Keyboard Power",
+        0x0067 => "Keypad =",
+        0x0068 => "Keyboard F13",
+        0x0069 => "Keyboard F14",
+        0x006A => "Keyboard F15",
+        0x006B => "Keyboard F16",
+        0x006C => "Keyboard F17",
+        0x006D => "Keyboard F18",
+        0x006E => "Keyboard F19",
+        0x006F => "Keyboard F20",
+        0x0070 => "Keyboard F21",
+        0x0071 => "Keyboard F22",
+        0x0072 => "Keyboard F23",
+        0x0073 => "Keyboard F24",
+        0x0074 => "Keyboard Execute",
+        0x0075 => "Keyboard Help",
+        0x0076 => "Keyboard Menu",
+        0x0077 => "Keyboard Select",
+        0x0078 => "Keyboard Stop",
+        0x0079 => "Keyboard Again",
+        0x007A => "Keyboard Undo",
+        0x007B => "Keyboard Cut",
+        0x007C => "Keyboard Copy",
+        0x007D => "Keyboard Paste",
+        0x007E => "Keyboard Find",
+        0x007F => "Keyboard Mute",
+        0x0080 => "Keyboard Volume Up",
+        0x0081 => "Keyboard Volume Down",
+        0x0082 => "Keyboard Locking Caps Lock",
+        0x0083 => "Keyboard Locking Num Lock",
+        0x0084 => "Keyboard Locking Scroll Lock",
+        0x0085 => "Keypad Comma",
+        0x0086 => "Keypad Equal Sign",
+        0x0087 => "Keyboard International 1",
+        0x0088 => "Keyboard International 2",
+        0x0089 => "Keyboard International 3",
+        0x008A => "Keyboard International 4",
+        0x008B => "Keyboard International 5",
+        0x008C => "Keyboard International 6",
+        0x008D => "Keyboard International 7",
+        0x008E => "Keyboard International 8",
+        0x008F => "Keyboard International 9",
+        0x0090 => "Keyboard LANG 1",
+        0x0091 => "Keyboard LANG 2",
+        0x0092 => "Keyboard LANG 3",
+        0x0093 => "Keyboard LANG 4",
+        0x0094 => "Keyboard LANG 5",
+        0x0095 => "Keyboard LANG 6",
+        0x0096 => "Keyboard LANG 7",
+        0x0097 => "Keyboard LANG 8",
+        0x0098 => "Keyboard LANG 9",
+        0x0099 => "Keyboard Alternate Erase",
+        0x009A => "Keyboard SysReq/Attention",
+        0x009B => "Keyboard Cancel",
+        0x009C => "Keyboard Clear",
+        0x009D => "Keyboard Prior",
+        0x009E => "Keyboard Return",
+        0x009F => "Keyboard Separator",
+        0x00A0 => "Keyboard Out",
+        0x00A1 => "Keyboard Oper",
+        0x00A2 => "Keyboard Clear/Again",
+        0x00A3 => "Keyboard CrSel/Props",
+        0x00A4 => "Keyboard ExSel",
+        0x00B0 => "Keypad 00",
+        0x00B1 => "Keypad 000",
+        0x00B2 => "Thousands Separator",
+        0x00B3 => "Decimal Separator",
+        0x00B4 => "Currency Unit",
+        0x00B5 => "Currency Sub-unit",
+        0x00B6 => "Keypad (",
+        0x00B7 => "Keypad )",
+        0x00B8 => "Keypad {",
+        0x00B9 => "Keypad }",
+        0x00BA => "Keypad Tab",
+        0x00BB => "Keypad Backspace",
+        0x00BC => "Keypad A",
+        0x00BD => "Keypad B",
+        0x00BE => "Keypad C",
+        0x00BF => "Keypad D",
+        0x00C0 => "Keypad E",
+        0x00C1 => "Keypad F",
+        0x00C2 => "Keypad XOR",
+        0x00C3 => "Keypad ^",
+        0x00C4 => "Keypad %",
+        0x00C5 => "Keypad <",
+        0x00C6 => "Keypad >",
+        0x00C7 => "Keypad &",
+        0x00C8 => "Keypad &&",
+        0x00C9 => "Keypad |",
+        0x00CA => "Keypad ||",
+        0x00CB => "Keypad :",
+        0x00CC => "Keypad #",
+        0x00CD => "Keypad Space",
+        0x00CE => "Keypad @",
+        0x00CF => "Keypad !",
+        0x00D0 => "Keypad Memory Store",
+        0x00D1 => "Keypad Memory Recall",
+        0x00D2 => "Keypad Memory Clear",
+        0x00D3 => "Keypad Memory Add",
+        0x00D4 => "Keypad Memory Subtract",
+        0x00D5 => "Keypad Memory Multiply",
+        0x00D6 => "Keypad Memory Divide",
+        0x00D7 => "Keypad +/-",
+        0x00D8 => "Keypad Clear",
+        0x00D9 => "Keypad Clear Entry",
+        0x00DA => "Keypad Binary",
+        0x00DB => "Keypad Octal",
+        0x00DC => "Keypad Decimal",
+        0x00DD => "Keypad Hexadecimal",
+        0x00E0 => "Keyboard LeftControl",
+        0x00E1 => "Keyboard LeftShift",
+        0x00E2 => "Keyboard LeftAlt",
+        0x00E3 => "Keyboard Left GUI",
+        0x00E4 => "Keyboard RightControl",
+        0x00E5 => "Keyboard RightShift",
+        0x00E6 => "Keyboard RightAlt",
+        0x00E7 => "Keyboard Right GUI",
+    ];
+    0x0008, "LED" => named[
+        0x0001 => "Num Lock",
+        0x0002 => "Caps Lock",
+        0x0003 => "Scroll Lock",
+        0x0004 => "Compose",
+        0x0005 => "Kana",
+        0x0006 => "Power",
+        0x0007 => "Shift",
+        0x0008 => "Do Not Disturb",
+        0x0009 => "Mute",
+        0x000A => "Tone Enable",
+        0x000B => "High Cut Filter",
+        0x000C => "Low Cut Filter",
+        0x000D => "Equalizer Enable",
+        0x000E => "Sound Field On",
+        0x000F => "Surround On",
+        0x0010 => "Repeat",
+        0x0011 => "Stereo",
+        0x0012 => "Sampling Rate Detect",
+        0x0013 => "Spinning",
+        0x0014 => "CAV",
+        0x0015 => "CLV",
+        0x0016 => "Recording Format Detect",
+        0x0017 => "Off-Hook",
+        0x0018 => "Ring",
+        0x0019 => "Message Waiting",
+        0x001A => "Data Mode",
+        0x001B => "Battery Operation",
+        0x001C => "Battery OK",
+        0x001D => "Battery Low",
+        0x001E => "Speaker",
+        0x001F => "Headset",
+        0x0020 => "Hold",
+        0x0021 => "Microphone",
+        0x0022 => "Coverage",
+        0x0023 => "Night Mode",
+        0x0024 => "Send Calls",
+        0x0025 => "Call Pickup",
+        0x0026 => "Conference",
+        0x0027 => "Stand-by",
+        0x0028 => "Camera On",
+        0x0029 => "Camera Off",
+        0x002A => "On-Line",
+        0x002B => "Off-Line",
+        0x002C => "Busy",
+        0x002D => "Ready",
+        0x002E => "Paper-Out",
+        0x002F => "Paper-Jam",
+        0x0030 => "Remote",
+        0x0031 => "Forward",
+        0x0032 => "Reverse",
+        0x0033 => "Stop",
+        0x0034 => "Rewind",
+        0x0035 => "Fast Forward",
+        0x0036 => "Play",
+        0x0037 => "Pause",
+        0x0038 => "Record",
+        0x0039 => "Error",
+        0x003A => "Usage Selected Indicator",
+        0x003B => "Usage In Use Indicator",
+        0x003C => "Usage Multi Mode Indicator",
+        0x003D => "Indicator On",
+        0x003E => "Indicator Flash",
+        0x003F => "Indicator Slow Blink",
+        0x0040 => "Indicator Fast Blink",
+        0x0041 => "Indicator Off",
+        0x0042 => "Flash On Time",
+        0x0043 => "Slow Blink On Time",
+        0x0044 => "Slow Blink Off Time",
+        0x0045 => "Fast Blink On Time",
+        0x0046 => "Fast Blink Off Time",
+        0x0047 => "Usage Indicator Color",
+        0x0048 => "Indicator Red",
+        0x0049 => "Indicator Green",
+        0x004A => "Indicator Amber",
+        0x004B => "Generic Indicator",
+        0x004C => "System Suspend",
+        0x004D => "External Power Connected",
+        0x004E => "Indicator Blue",
+        0x004F => "Indicator Orange",
+        0x0050 => "Good Status",
+        0x0051 => "Warning Status",
+        0x0052 => "RGB LED",
+        0x0053 => "Red LED Channel",
+        0x0054 => "Blue LED Channel",
+        0x0055 => "Green LED Channel",
+        0x0056 => "LED Intensity",
+        0x0057 => "System Microphone Mute",
+        0x0060 => "Player Indicator",
+        0x0061 => "Player 1",
+        0x0062 => "Player 2",
+        0x0063 => "Player 3",
+        0x0064 => "Player 4",
+        0x0065 => "Player 5",
+        0x0066 => "Player 6",
+        0x0067 => "Player 7",
+        0x0068 => "Player 8",
+    ];
+    0x0009, "Button" => numbered("Button");
+    0x000A, "Ordinal" => numbered("Instance");
+    0x000B, "Telephony Device" => named[
+        0x0001 => "Phone",
+        0x0002 => "Answering Machine",
+        0x0003 => "Message Controls",
+        0x0004 => "Handset",
+        0x0005 => "Headset",
+        0x0006 => "Telephony Key Pad",
+        0x0007 => "Programmable Button",
+        0x0020 => "Hook Switch",
+        0x0021 => "Flash",
+        0x0022 => "Feature",
+        0x0023 => "Hold",
+        0x0024 => "Redial",
+        0x0025 => "Transfer",
+        0x0026 => "Drop",
+        0x0027 => "Park",
+        0x0028 => "Forward Calls",
+        0x0029 => "Alternate Function",
+        0x002A => "Line",
+        0x002B => "Speaker Phone",
+        0x002C => "Conference",
+        0x002D => "Ring Enable",
+        0x002E => "Ring Select",
+        0x002F => "Phone Mute",
+        0x0030 => "Caller ID",
+        0x0031 => "Send",
+        0x0050 => "Speed Dial",
+        0x0051 => "Store Number",
+        0x0052 => "Recall Number",
+        0x0053 => "Phone Directory",
+        0x0070 => "Voice Mail",
+        0x0071 => "Screen Calls",
+        0x0072 => "Do Not Disturb",
+        0x0073 => "Message",
+        0x0074 => "Answer On/Off",
+        0x0090 => "Inside Dial Tone",
+        0x0091 => "Outside Dial Tone",
+        0x0092 => "Inside Ring Tone",
+        0x0093 => "Outside Ring Tone",
+        0x0094 => "Priority Ring Tone",
+        0x0095 => "Inside Ringback",
+        0x0096 => "Priority Ringback",
+        0x0097 => "Line Busy Tone",
+        0x0098 => "Reorder Tone",
+        0x0099 => "Call Waiting Tone",
+        0x009A => "Confirmation Tone 1",
+        0x009B => "Confirmation Tone 2",
+        0x009C => "Tones Off",
+        0x009D => "Outside Ringback",
+        0x009E => "Ringer",
+        0x00B0 => "Phone Key 0",
+        0x00B1 => "Phone Key 1",
+        0x00B2 => "Phone Key 2",
+        0x00B3 => "Phone Key 3",
+        0x00B4 => "Phone Key 4",
+        0x00B5 => "Phone Key 5",
+        0x00B6 => "Phone Key 6",
+        0x00B7 => "Phone Key 7",
+        0x00B8 => "Phone Key 8",
+        0x00B9 => "Phone Key 9",
+        0x00BA => "Phone Key Star",
+        0x00BB => "Phone Key Pound",
+        0x00BC => "Phone Key A",
+        0x00BD => "Phone Key B",
+        0x00BE => "Phone Key C",
+        0x00BF => "Phone Key D",
+        0x00C0 => "Phone Call History Key",
+        0x00C1 => "Phone Caller ID Key",
+        0x00C2 => "Phone Settings Key",
+        0x00F0 => "Host Control",
+        0x00F1 => "Host Available",
+        0x00F2 => "Host Call Active",
+        0x00F3 => "Activate Handset Audio",
+        0x00F4 => "Ring Type",
+        0x00F5 => "Re-dialable Phone Number",
+        0x00F8 => "Stop Ring Tone",
+        0x00F9 => "PSTN Ring Tone",
+        0x00FA => "Host Ring Tone",
+        0x00FB => "Alert Sound Error",
+        0x00FC => "Alert Sound Confirm",
+        0x00FD => "Alert Sound Notification",
+        0x00FE => "Silent Ring",
+        0x0108 => "Email Message Waiting",
+        0x0109 => "Voicemail Message Waiting",
+        0x010A => "Host Hold",
+        0x0110 => "Incoming Call History Count",
+        0x0111 => "Outgoing Call History Count",
+        0x0112 => "Incoming Call History",
+        0x0113 => "Outgoing Call History",
+        0x0114 => "Phone Locale",
+    ];
+    0x000C, "Consumer" => named[
+        0x0001 => "Consumer Control",
+        0x0002 => "Numeric Key Pad",
+        0x0003 => "Programmable Buttons",
+        0x0004 => "Microphone",
+        0x0005 => "Headphone",
+        0x0006 => "Graphic Equalizer",
+        0x0020 => "+10",
+        0x0021 => "+100",
+        0x0022 => "AM/PM",
+        0x0030 => "Power",
+        0x0031 => "Reset",
+        0x0032 => "Sleep",
+        0x0033 => "Sleep After",
+        0x0034 => "Sleep Mode",
+        0x0035 => "Illumination",
+        0x0036 => "Function Buttons",
+        0x0040 => "Menu",
+        0x0041 => "Menu Pick",
+        0x0042 => "Menu Up",
+        0x0043 => "Menu Down",
+        0x0044 => "Menu Left",
+        0x0045 => "Menu Right",
+        0x0046 => "Menu Escape",
+        0x0047 => "Menu Value Increase",
+        0x0048 => "Menu Value Decrease",
+        0x0060 => "Data On Screen",
+        0x0061 => "Closed Caption",
+        0x0062 => "Closed Caption Select",
+        0x0063 => "VCR/TV",
+        0x0064 => "Broadcast Mode",
+        0x0065 => "Snapshot",
+        0x0066 => "Still",
+        0x0067 => "Picture-in-Picture Toggle",
+        0x0068 => "Picture-in-Picture Swap",
+        0x0069 => "Red Menu Button",
+        0x006A => "Green Menu Button",
+        0x006B => "Blue Menu Button",
+        0x006C => "Yellow Menu Button",
+        0x006D => "Aspect",
+        0x006E => "3D Mode Select",
+        0x006F => "Display Brightness Increment",
+        0x0070 => "Display Brightness Decrement",
+        0x0071 => "Display Brightness",
+        0x0072 => "Display Backlight Toggle",
+        0x0073 => "Display Set Brightness to Minimum",
+        0x0074 => "Display Set Brightness to Maximum",
+        0x0075 => "Display Set Auto Brightness",
+        0x0076 => "Camera Access Enabled",
+        0x0077 => "Camera Access Disabled",
+        0x0078 => "Camera Access Toggle",
+        0x0079 => "Keyboard Brightness Increment",
+        0x007A => "Keyboard Brightness Decrement",
+        0x007B => "Keyboard Backlight Set Level",
+        0x007C => "Keyboard Backlight OOC",
+        0x007D => "Keyboard Backlight Set Minimum",
+        0x007E => "Keyboard Backlight Set Maximum",
+        0x007F => "Keyboard Backlight Auto",
+        0x0080 => "Selection",
+        0x0081 => "Assign Selection",
+        0x0082 => "Mode Step",
+        0x0083 => "Recall Last",
+        0x0084 => "Enter Channel",
+        0x0085 => "Order Movie",
+        0x0086 => "Channel",
+        0x0087 => "Media Selection",
+        0x0088 => "Media Select Computer",
+        0x0089 => "Media Select TV",
+        0x008A => "Media Select WWW",
+        0x008B => "Media Select DVD",
+        0x008C => "Media Select Telephone",
+        0x008D => "Media Select Program Guide",
+        0x008E => "Media Select Video Phone",
+        0x008F => "Media Select Games",
+        0x0090 => "Media Select Messages",
+        0x0091 => "Media Select CD",
+        0x0092 => "Media Select VCR",
+        0x0093 => "Media Select Tuner",
+        0x0094 => "Quit",
+        0x0095 => "Help",
+        0x0096 => "Media Select Tape",
+        0x0097 => "Media Select Cable",
+        0x0098 => "Media Select Satellite",
+        0x0099 => "Media Select Security",
+        0x009A => "Media Select Home",
+        0x009B => "Media Select Call",
+        0x009C => "Channel Increment",
+        0x009D => "Channel Decrement",
+        0x009E => "Media Select SAP",
+        0x00A0 => "VCR Plus",
+        0x00A1 => "Once",
+        0x00A2 => "Daily",
+        0x00A3 => "Weekly",
+        0x00A4 => "Monthly",
+        0x00B0 => "Play",
+        0x00B1 => "Pause",
+        0x00B2 => "Record",
+        0x00B3 => "Fast Forward",
+        0x00B4 => "Rewind",
+        0x00B5 => "Scan Next Track",
+        0x00B6 => "Scan Previous Track",
+        0x00B7 => "Stop",
+        0x00B8 => "Eject",
+        0x00B9 => "Random Play",
+        0x00BA => "Select Disc",
+        0x00BB => "Enter Disc",
+        0x00BC => "Repeat",
+        0x00BD => "Tracking",
+        0x00BE => "Track Normal",
+        0x00BF => "Slow Tracking",
+        0x00C0 => "Frame Forward",
+        0x00C1 => "Frame Back",
+        0x00C2 => "Mark",
+        0x00C3 => "Clear Mark",
+        0x00C4 => "Repeat From Mark",
+        0x00C5 => "Return To Mark",
+        0x00C6 => "Search Mark Forward",
+        0x00C7 => "Search Mark Backwards",
+        0x00C8 => "Counter Reset",
+        0x00C9 => "Show Counter",
+        0x00CA => "Tracking Increment",
+        0x00CB => "Tracking Decrement",
+        0x00CC => "Stop/Eject",
+        0x00CD => "Play/Pause",
+        0x00CE => "Play/Skip",
+        0x00CF => "Voice Command",
+        0x00D0 => "Invoke Capture Interface",
+        0x00D1 => "Start or Stop Game Recording",
+        0x00D2 => "Historical Game Capture",
+        0x00D3 => "Capture Game Screenshot",
+        0x00D4 => "Show or Hide Recording Indicator",
+        0x00D5 => "Start or Stop Microphone Capture",
+        0x00D6 => "Start or Stop Camera Capture",
+        0x00D7 => "Start or Stop Game Broadcast",
+        0x00D8 => "Start or Stop Voice Dictation Session",
+        0x00D9 => "Invoke/Dismiss Emoji Picker",
+        0x00E0 => "Volume",
+        0x00E1 => "Balance",
+        0x00E2 => "Mute",
+        0x00E3 => "Bass",
+        0x00E4 => "Treble",
+        0x00E5 => "Bass Boost",
+        0x00E6 => "Surround Mode",
+        0x00E7 => "Loudness",
+        0x00E8 => "MPX",
+        0x00E9 => "Volume Increment",
+        0x00EA => "Volume Decrement",
+        0x00F0 => "Speed Select",
+        0x00F1 => "Playback Speed",
+        0x00F2 => "Standard Play",
+        0x00F3 => "Long Play",
+        0x00F4 => "Extended Play",
+        0x00F5 => "Slow",
+        0x0100 => "Fan Enable",
+        0x0101 => "Fan Speed",
+        0x0102 => "Light Enable",
+        0x0103 => "Light Illumination Level",
+        0x0104 => "Climate Control Enable",
+        0x0105 => "Room Temperature",
+        0x0106 => "Security Enable",
+        0x0107 => "Fire Alarm",
+        0x0108 => "Police Alarm",
+        0x0109 => "Proximity",
+        0x010A => "Motion",
+        0x010B => "Duress Alarm",
+        0x010C => "Holdup Alarm",
+        0x010D => "Medical Alarm",
+        0x0150 => "Balance Right",
+        0x0151 => "Balance Left",
+        0x0152 => "Bass Increment",
+        0x0153 => "Bass Decrement",
+        0x0154 => "Treble Increment",
+        0x0155 => "Treble Decrement",
+        0x0160 => "Speaker System",
+        0x0161 => "Channel Left",
+        0x0162 => "Channel Right",
+        0x0163 => "Channel Center",
+        0x0164 => "Channel Front",
+        0x0165 => "Channel Center Front",
+        0x0166 => "Channel Side",
+        0x0167 => "Channel Surround",
+        0x0168 => "Channel Low Frequency Enhancement",
+        0x0169 => "Channel Top",
+        0x016A => "Channel Unknown",
+        0x0170 => "Sub-channel",
+        0x0171 => "Sub-channel Increment",
+        0x0172 => "Sub-channel Decrement",
+        0x0173 => "Alternate Audio Increment",
+        0x0174 => "Alternate Audio Decrement",
+        0x0180 => "Application Launch Buttons",
+        0x0181 => "AL Launch Button Configuration Tool",
+        0x0182 => "AL Programmable Button Configuration",
+        0x0183 => "AL Consumer Control Configuration",
+        0x0184 => "AL Word Processor",
+        0x0185 => "AL Text Editor",
+        0x0186 => "AL Spreadsheet",
+        0x0187 => "AL Graphics Editor",
+        0x0188 => "AL Presentation App",
+        0x0189 => "AL Database App",
+        0x018A => "AL Email Reader",
+        0x018B => "AL Newsreader",
+        0x018C => "AL Voicemail",
+        0x018D => "AL Contacts/Address Book",
+        0x018E => "AL Calendar/Schedule",
+        0x018F => "AL Task/Project Manager",
+        0x0190 => "AL Log/Journal/Timecard",
+        0x0191 => "AL Checkbook/Finance",
+        0x0192 => "AL Calculator",
+        0x0193 => "AL A/V Capture/Playback",
+        0x0194 => "AL Local Machine Browser",
+        0x0195 => "AL LAN/WAN Browser",
+        0x0196 => "AL Internet Browser",
+        0x0197 => "AL Remote Networking/ISP Connect",
+        0x0198 => "AL Network Conference",
+        0x0199 => "AL Network Chat",
+        0x019A => "AL Telephony/Dialer",
+        0x019B => "AL Logon",
+        0x019C => "AL Logoff",
+        0x019D => "AL Logon/Logoff",
+        0x019E => "AL Terminal Lock/Screensaver",
+        0x019F => "AL Control Panel",
+        0x01A0 => "AL Command Line Processor/Run",
+        0x01A1 => "AL Process/Task Manager",
+        0x01A2 => "AL Select Task/Application",
+        0x01A3 => "AL Next Task/Application",
+        0x01A4 => "AL Previous Task/Application",
+        0x01A5 => "AL Preemptive Halt Task/Application",
+        0x01A6 => "AL Integrated Help Center",
+        0x01A7 => "AL Documents",
+        0x01A8 => "AL Thesaurus",
+        0x01A9 => "AL Dictionary",
+        0x01AA => "AL Desktop",
+        0x01AB => "AL Spell Check",
+        0x01AC => "AL Grammar Check",
+        0x01AD => "AL Wireless Status",
+        0x01AE => "AL Keyboard Layout",
+        0x01AF => "AL Virus Protection",
+        0x01B0 => "AL Encryption",
+        0x01B1 => "AL Screen Saver",
+        0x01B2 => "AL Alarms",
+        0x01B3 => "AL Clock",
+        0x01B4 => "AL File Browser",
+        0x01B5 => "AL Power Status",
+        0x01B6 => "AL Image Browser",
+        0x01B7 => "AL Audio Browser",
+        0x01B8 => "AL Movie Browser",
+        0x01B9 => "AL Digital Rights Manager",
+        0x01BA => "AL Digital Wallet",
+        0x01BC => "AL Instant Messaging",
+        0x01BD => "AL OEM Features/ Tips/Tutorial Browser",
+        0x01BE => "AL OEM Help",
+        0x01BF => "AL Online Community",
+        0x01C0 => "AL Entertainment Content Browser",
+        0x01C1 => "AL Online Shopping Browser",
+        0x01C2 => "AL SmartCard Information/Help",
+        0x01C3 => "AL Market Monitor/Finance Browser",
+        0x01C4 => "AL Customized Corporate News Browser",
+        0x01C5 => "AL Online Activity Browser",
+        0x01C6 => "AL Research/Search Browser",
+        0x01C7 => "AL Audio Player",
+        0x01C8 => "AL Message Status",
+        0x01C9 => "AL Contact Sync",
+        0x01CA => "AL Navigation",
+        0x01CB => "AL Context-aware Desktop Assistant",
+        0x0200 => "Generic GUI Application Controls",
+        0x0201 => "AC New",
+        0x0202 => "AC Open",
+        0x0203 => "AC Close",
+        0x0204 => "AC Exit",
+        0x0205 => "AC Maximize",
+        0x0206 => "AC Minimize",
+        0x0207 => "AC Save",
+        0x0208 => "AC Print",
+        0x0209 => "AC Properties",
+        0x021A => "AC Undo",
+        0x021B => "AC Copy",
+        0x021C => "AC Cut",
+        0x021D => "AC Paste",
+        0x021E => "AC Select All",
+        0x021F => "AC Find",
+        0x0220 => "AC Find and Replace",
+        0x0221 => "AC Search",
+        0x0222 => "AC Go To",
+        0x0223 => "AC Home",
+        0x0224 => "AC Back",
+        0x0225 => "AC Forward",
+        0x0226 => "AC Stop",
+        0x0227 => "AC Refresh",
+        0x0228 => "AC Previous Link",
+        0x0229 => "AC Next Link",
+        0x022A => "AC Bookmarks",
+        0x022B => "AC History",
+        0x022C => "AC Subscriptions",
+        0x022D => "AC Zoom In",
+        0x022E => "AC Zoom Out",
+        0x022F => "AC Zoom",
+        0x0230 => "AC Full Screen View",
+        0x0231 => "AC Normal View",
+        0x0232 => "AC View Toggle",
+        0x0233 => "AC Scroll Up",
+        0x0234 => "AC Scroll Down",
+        0x0235 => "AC Scroll",
+        0x0236 => "AC Pan Left",
+        0x0237 => "AC Pan Right",
+        0x0238 => "AC Pan",
+        0x0239 => "AC New Window",
+        0x023A => "AC Tile Horizontally",
+        0x023B => "AC Tile Vertically",
+        0x023C => "AC Format",
+        0x023D => "AC Edit",
+        0x023E => "AC Bold",
+        0x023F => "AC Italics",
+        0x0240 => "AC Underline",
+        0x0241 => "AC Strikethrough",
+        0x0242 => "AC Subscript",
+        0x0243 => "AC Superscript",
+        0x0244 => "AC All Caps",
+        0x0245 => "AC Rotate",
+        0x0246 => "AC Resize",
+        0x0247 => "AC Flip Horizontal",
+        0x0248 => "AC Flip Vertical",
+        0x0249 => "AC Mirror Horizontal",
+        0x024A => "AC Mirror Vertical",
+        0x024B => "AC Font Select",
+        0x024C => "AC Font Color",
+        0x024D => "AC Font Size",
+        0x024E => "AC Justify Left",
+        0x024F => "AC Justify Center H",
+        0x0250 => "AC Justify Right",
+        0x0251 => "AC Justify Block H",
+        0x0252 => "AC Justify Top",
+        0x0253 => "AC Justify Center V",
+        0x0254 => "AC Justify Bottom",
+        0x0255 => "AC Justify Block V",
+        0x0256 => "AC Indent Decrease",
+        0x0257 => "AC Indent Increase",
+        0x0258 => "AC Numbered List",
+        0x0259 => "AC Restart Numbering",
+        0x025A => "AC Bulleted List",
+        0x025B => "AC Promote",
+        0x025C => "AC Demote",
+        0x025D => "AC Yes",
+        0x025E => "AC No",
+        0x025F => "AC Cancel",
+        0x0260 => "AC Catalog",
+        0x0261 => "AC Buy/Checkout",
+        0x0262 => "AC Add to Cart",
+        0x0263 => "AC Expand",
+        0x0264 => "AC Expand All",
+        0x0265 => "AC Collapse",
+        0x0266 => "AC Collapse All",
+        0x0267 => "AC Print Preview",
+        0x0268 => "AC Paste Special",
+        0x0269 => "AC Insert Mode",
+        0x026A => "AC Delete",
+        0x026B => "AC Lock",
+        0x026C => "AC Unlock",
+        0x026D => "AC Protect",
+        0x026E => "AC Unprotect",
+        0x026F => "AC Attach Comment",
+        0x0270 => "AC Delete Comment",
+        0x0271 => "AC View Comment",
+        0x0272 => "AC Select Word",
+        0x0273 => "AC Select Sentence",
+        0x0274 => "AC Select Paragraph",
+        0x0275 => "AC Select Column",
+        0x0276 => "AC Select Row",
+        0x0277 => "AC Select Table",
+        0x0278 => "AC Select Object",
+        0x0279 => "AC Redo/Repeat",
+        0x027A => "AC Sort",
+        0x027B => "AC Sort Ascending",
+        0x027C => "AC Sort Descending",
+        0x027D => "AC Filter",
+        0x027E => "AC Set Clock",
+        0x027F => "AC View Clock",
+        0x0280 => "AC Select Time Zone",
+        0x0281 => "AC Edit Time Zones",
+        0x0282 => "AC Set Alarm",
+        0x0283 => "AC Clear Alarm",
+        0x0284 => "AC Snooze Alarm",
+        0x0285 => "AC Reset Alarm",
+        0x0286 => "AC Synchronize",
+        0x0287 => "AC Send/Receive",
+        0x0288 => "AC Send To",
+        0x0289 => "AC Reply",
+        0x028A => "AC Reply All",
+        0x028B => "AC Forward Msg",
+        0x028C => "AC Send",
+        0x028D => "AC Attach File",
+        0x028E => "AC Upload",
+        0x028F => "AC Download (Save Target As)",
+        0x0290 => "AC Set Borders",
+        0x0291 => "AC Insert Row",
+        0x0292 => "AC Insert Column",
+        0x0293 => "AC Insert File",
+        0x0294 => "AC Insert Picture",
+        0x0295 => "AC Insert Object",
+        0x0296 => "AC Insert Symbol",
+        0x0297 => "AC Save and Close",
+        0x0298 => "AC Rename",
+        0x0299 => "AC Merge",
+        0x029A => "AC Split",
+        0x029B => "AC Distribute Horizontally",
+        0x029C => "AC Distribute Vertically",
+        0x029D => "AC Next Keyboard Layout Select",
+        0x029E => "AC Navigation Guidance",
+        0x029F => "AC Desktop Show All Windows",
+        0x02A0 => "AC Soft Key Left",
+        0x02A1 => "AC Soft Key Right",
+        0x02A2 => "AC Desktop Show All Applications",
+        0x02B0 => "AC Idle Keep Alive",
+        0x02C0 => "Extended Keyboard Attributes Collection",
+        0x02C1 => "Keyboard Form Factor",
+        0x02C2 => "Keyboard Key Type",
+        0x02C3 => "Keyboard Physical Layout",
+        0x02C4 => "Vendor-Specific Keyboard Physical Layout",
+        0x02C5 => "Keyboard IETF Language Tag Index",
+        0x02C6 => "Implemented Keyboard Input Assist Controls",
+        0x02C7 => "Keyboard Input Assist Previous",
+        0x02C8 => "Keyboard Input Assist Next",
+        0x02C9 => "Keyboard Input Assist Previous Group",
+        0x02CA => "Keyboard Input Assist Next Group",
+        0x02CB => "Keyboard Input Assist Accept",
+        0x02CC => "Keyboard Input Assist Cancel",
+        0x02D0 => "Privacy Screen Toggle",
+        0x02D1 => "Privacy Screen Level Decrement",
+        0x02D2 => "Privacy Screen Level Increment",
+        0x02D3 => "Privacy Screen Level Minimum",
+        0x02D4 => "Privacy Screen Level Maximum",
+    ];
+    0x000D, "Digitizers" => named[
+        0x0001 => "Digitizer",
+        0x0002 => "Pen",
+        0x0003 => "Light Pen",
+        0x0004 => "Touch Screen",
+        0x0005 => "Touch Pad",
+        0x0006 => "Whiteboard",
+        0x0007 => "Coordinate Measuring Machine",
+        0x0008 => "3D Digitizer",
+        0x0009 => "Stereo Plotter",
+        0x000A => "Articulated Arm",
+        0x000B => "Armature",
+        0x000C => "Multiple Point Digitizer",
+        0x000D => "Free Space Wand",
+        0x000E => "Device Configuration",
+        0x000F => "Capacitive Heat Map Digitizer",
+        0x0020 => "Stylus",
+        0x0021 => "Puck",
+        0x0022 => "Finger",
+        0x0023 => "Device Settings",
+        0x0024 => "Character Gesture",
+        0x0030 => "Tip Pressure",
+        0x0031 => "Barrel Pressure",
+        0x0032 => "In Range",
+        0x0033 => "Touch",
+        0x0034 => "Untouch",
+        0x0035 => "Tap",
+        0x0036 => "Quality",
+        0x0037 => "Data Valid",
+        0x0038 => "Transducer Index",
+        0x0039 => "Tablet Function Keys",
+        0x003A => "Program Change Keys",
+        0x003B => "Battery Strength",
+        0x003C => "Invert",
+        0x003D => "X Tilt",
+        0x003E => "Y Tilt",
+        0x003F => "Azimuth",
+        0x0040 => "Altitude",
+        0x0041 => "Twist",
+        0x0042 => "Tip Switch",
+        0x0043 => "Secondary Tip Switch",
+        0x0044 => "Barrel Switch",
+        0x0045 => "Eraser",
+        0x0046 => "Tablet Pick",
+        0x0047 => "Touch Valid",
+        0x0048 => "Width",
+        0x0049 => "Height",
+        0x0051 => "Contact Identifier",
+        0x0052 => "Device Mode",
+        0x0053 => "Device Identifier",
+        0x0054 => "Contact Count",
+        0x0055 => "Contact Count Maximum",
+        0x0056 => "Scan Time",
+        0x0057 => "Surface Switch",
+        0x0058 => "Button Switch",
+        0x0059 => "Pad Type",
+        0x005A => "Secondary Barrel Switch",
+        0x005B => "Transducer Serial Number",
+        0x005C => "Preferred Color",
+        0x005D => "Preferred Color is Locked",
+        0x005E => "Preferred Line Width",
+        0x005F => "Preferred Line Width is Locked",
+        0x0060 => "Latency Mode",
+        0x0061 => "Gesture Character Quality",
+        0x0062 => "Character Gesture Data Length",
+        0x0063 => "Character Gesture Data",
+        0x0064 => "Gesture Character Encoding",
+        0x0065 => "UTF8 Character Gesture Encoding",
+        0x0066 => "UTF16 Little Endian Character Gesture Encoding",
+        0x0067 => "UTF16 Big Endian Character Gesture Encoding",
+        0x0068 => "UTF32 Little Endian Character Gesture Encoding",
+        0x0069 => "UTF32 Big Endian Character Gesture Encoding",
+        0x006A => "Capacitive Heat Map Protocol Vendor ID",
+        0x006B => "Capacitive Heat Map Protocol Version",
+        0x006C => "Capacitive Heat Map Frame Data",
+        0x006D => "Gesture Character Enable",
+        0x006E => "Transducer Serial Number Part 2",
+        0x006F => "No Preferred Color",
+        0x0070 => "Preferred Line Style",
+        0x0071 => "Preferred Line Style is Locked",
+        0x0072 => "Ink",
+        0x0073 => "Pencil",
+        0x0074 => "Highlighter",
+        0x0075 => "Chisel Marker",
+        0x0076 => "Brush",
+        0x0077 => "No Preference",
+        0x0080 => "Digitizer Diagnostic",
+        0x0081 => "Digitizer Error",
+        0x0082 => "Err Normal Status",
+        0x0083 => "Err Transducers Exceeded",
+        0x0084 => "Err Full Trans Features Unavailable",
+        0x0085 => "Err Charge Low",
+        0x0090 => "Transducer Software Info",
+        0x0091 => "Transducer Vendor Id",
+        0x0092 => "Transducer Product Id",
+        0x0093 => "Device Supported Protocols",
+        0x0094 => "Transducer Supported Protocols",
+        0x0095 => "No Protocol",
+        0x0096 => "Wacom AES Protocol",
+        0x0097 => "USI Protocol",
+        0x0098 => "Microsoft Pen Protocol",
+        0x00A0 => "Supported Report Rates",
+        0x00A1 => "Report Rate",
+        0x00A2 => "Transducer Connected",
+        0x00A3 => "Switch Disabled",
+        0x00A4 => "Switch Unimplemented",
+        0x00A5 => "Transducer Switches",
+        0x00A6 => "Transducer Index Selector",
+        0x00B0 => "Button Press Threshold",
+    ];
+    0x000E, "Haptics" => named[
+        0x0001 => "Simple Haptic Controller",
+        0x0010 => "Waveform List",
+        0x0011 => "Duration List",
+        0x0020 => "Auto Trigger",
+        0x0021 => "Manual Trigger",
+        0x0022 => "Auto Trigger Associated Control",
+        0x0023 => "Intensity",
+        0x0024 => "Repeat Count",
+        0x0025 => "Retrigger Period",
+        0x0026 => "Waveform Vendor Page",
+        0x0027 => "Waveform Vendor ID",
+        0x0028 => "Waveform Cutoff Time",
+        0x1001 => "Waveform None",
+        0x1002 => "Waveform Stop",
+        0x1003 => "Waveform Click",
+        0x1004 => "Waveform Buzz Continuous",
+        0x1005 => "Waveform Rumble Continuous",
+        0x1006 => "Waveform Press",
+        0x1007 => "Waveform Release",
+        0x1008 => "Waveform Hover",
+        0x1009 => "Waveform Success",
+        0x100A => "Waveform Error",
+        0x100B => "Waveform Ink Continuous",
+        0x100C => "Waveform Pencil Continuous",
+        0x100D => "Waveform Marker Continuous",
+        0x100E => "Waveform Chisel Marker Continuous",
+        0x100F => "Waveform Brush Continuous",
+        0x1010 => "Waveform Eraser Continuous",
+        0x1011 => "Waveform Sparkle Continuous",
+    ];
+    0x000F, "Physical Input Device" => named[
+        0x0001 => "Physical Input Device",
+        0x0020 => "Normal",
+        0x0021 => "Set Effect Report",
+        0x0022 => "Effect Parameter Block Index",
+        0x0023 => "Parameter Block Offset",
+        0x0024 => "ROM Flag",
+        0x0025 => "Effect Type",
+        0x0026 => "ET Constant-Force",
+        0x0027 => "ET Ramp",
+        0x0028 => "ET Custom-Force",
+        0x0030 => "ET Square",
+        0x0031 => "ET Sine",
+        0x0032 => "ET Triangle",
+        0x0033 => "ET Sawtooth Up",
+        0x0034 => "ET Sawtooth Down",
+        0x0040 => "ET Spring",
+        0x0041 => "ET Damper",
+        0x0042 => "ET Inertia",
+        0x0043 => "ET Friction",
+        0x0050 => "Duration",
+        0x0051 => "Sample Period",
+        0x0052 => "Gain",
+        0x0053 => "Trigger Button",
+        0x0054 => "Trigger Repeat Interval",
+        0x0055 => "Axes Enable",
+        0x0056 => "Direction Enable",
+        0x0057 => "Direction",
+        0x0058 => "Type Specific Block Offset",
+        0x0059 => "Block Type",
+        0x005A => "Set Envelope Report",
+        0x005B => "Attack Level",
+        0x005C => "Attack Time",
+        0x005D => "Fade Level",
+        0x005E => "Fade Time",
+        0x005F => "Set Condition Report",
+        0x0060 => "Center-Point Offset",
+        0x0061 => "Positive Coefficient",
+        0x0062 => "Negative Coefficient",
+        0x0063 => "Positive Saturation",
+        0x0064 => "Negative Saturation",
+        0x0065 => "Dead Band",
+        0x0066 => "Download Force Sample",
+        0x0067 => "Isoch Custom-Force Enable",
+        0x0068 => "Custom-Force Data Report",
+        0x0069 => "Custom-Force Data",
+        0x006A => "Custom-Force Vendor Defined Data",
+        0x006B => "Set Custom-Force Report",
+        0x006C => "Custom-Force Data Offset",
+        0x006D => "Sample Count",
+        0x006E => "Set Periodic Report",
+        0x006F => "Offset",
+        0x0070 => "Magnitude",
+        0x0071 => "Phase",
+        0x0072 => "Period",
+        0x0073 => "Set Constant-Force Report",
+        0x0074 => "Set Ramp-Force Report",
+        0x0075 => "Ramp Start",
+        0x0076 => "Ramp End",
+        0x0077 => "Effect Operation Report",
+        0x0078 => "Effect Operation",
+        0x0079 => "Op Effect Start",
+        0x007A => "Op Effect Start Solo",
+        0x007B => "Op Effect Stop",
+        0x007C => "Loop Count",
+        0x007D => "Device Gain Report",
+        0x007E => "Device Gain",
+        0x007F => "PID Pool Report",
+        0x0080 => "RAM Pool Size",
+        0x0081 => "ROM Pool Size",
+        0x0082 => "ROM Effect Block Count",
+        0x0083 => "Simultaneous Effects Max",
+        0x0084 => "Pool Alignment",
+        0x0085 => "PID Pool Move Report",
+        0x0086 => "Move Source",
+        0x0087 => "Move Destination",
+        0x0088 => "Move Length",
+        0x0089 => "PID Block Load Report",
+        0x008B => "Block Load Status",
+        0x008C => "Block Load Success",
+        0x008D => "Block Load Full",
+        0x008E => "Block Load Error",
+        0x008F => "Block Handle",
+        0x0090 => "PID Block Free Report",
+        0x0091 => "Type Specific Block Handle",
+        0x0092 => "PID State Report",
+        0x0094 => "Effect Playing",
+        0x0095 => "PID Device Control Report",
+        0x0096 => "PID Device Control",
+        0x0097 => "DC Enable Actuators",
+        0x0098 => "DC Disable Actuators",
+        0x0099 => "DC Stop All Effects",
+        0x009A => "DC Device Reset",
+        0x009B => "DC Device Pause",
+        0x009C => "DC Device Continue",
+        0x009F => "Device Paused",
+        0x00A0 => "Actuators Enabled",
+        0x00A4 => "Safety Switch",
+        0x00A5 => "Actuator Override Switch",
+        0x00A6 => "Actuator Power",
+        0x00A7 => "Start Delay",
+        0x00A8 => "Parameter Block Size",
+        0x00A9 => "Device-Managed Pool",
+        0x00AA => "Shared Parameter Blocks",
+        0x00AB => "Create New Effect Report",
+        0x00AC => "RAM Pool Available",
+    ];
+    0x0011, "SoC" => named[
+        0x0001 => "SocControl",
+        0x0002 => "FirmwareTransfer",
+        0x0003 => "FirmwareFileId",
+        0x0004 => "FileOffsetInBytes",
+        0x0005 => "FileTransferSizeMaxInBytes",
+        0x0006 => "FilePayload",
+        0x0007 => "FilePayloadSizeInBytes",
+        0x0008 => "FilePayloadContainsLastBytes",
+        0x0009 => "FileTransferStop",
+        0x000A => "FileTransferTillEnd",
+    ];
+    0x0012, "Eye and Head Trackers" => named[
+        0x0001 => "Eye Tracker",
+        0x0002 => "Head Tracker",
+        0x0010 => "Tracking Data",
+        0x0011 => "Capabilities",
+        0x0012 => "Configuration",
+        0x0013 => "Status",
+        0x0014 => "Control",
+        0x0020 => "Sensor Timestamp",
+        0x0021 => "Position X",
+        0x0022 => "Position Y",
+        0x0023 => "Position Z",
+        0x0024 => "Gaze Point",
+        0x0025 => "Left Eye Position",
+        0x0026 => "Right Eye Position",
+        0x0027 => "Head Position",
+        0x0028 => "Head Direction Point",
+        0x0029 => "Rotation X",
+        0x002A => "Rotation Y",
+        0x002B => "Rotation Z",
+        0x0100 => "Tracker Quality",
+        0x0101 => "Minimum Tracking Distance",
+        0x0102 => "Optimum Tracking Distance",
+        0x0103 => "Maximum Tracking Distance",
+        0x0104 => "Maximum Screen Plane Width",
+        0x0105 => "Maximum Screen Plane Height",
+        0x0200 => "Display Manufacturer ID",
+        0x0201 => "Display Product ID",
+        0x0202 => "Display Serial Number",
+        0x0203 => "Display Manufacturer Date",
+        0x0204 => "Calibrated Screen Width",
+        0x0205 => "Calibrated Screen Height",
+        0x0300 => "Sampling Frequency",
+        0x0301 => "Configuration Status",
+        0x0400 => "Device Mode Request",
+    ];
+    0x0014, "Auxiliary Display" => named[
+        0x0001 => "Alphanumeric Display",
+        0x0002 => "Auxiliary Display",
+        0x0020 => "Display Attributes Report",
+        0x0021 => "ASCII Character Set",
+        0x0022 => "Data Read Back",
+        0x0023 => "Font Read Back",
+        0x0024 => "Display Control Report",
+        0x0025 => "Clear Display",
+        0x0026 => "Display Enable",
+        0x0027 => "Screen Saver Delay",
+        0x0028 => "Screen Saver Enable",
+        0x0029 => "Vertical Scroll",
+        0x002A => "Horizontal Scroll",
+        0x002B => "Character Report",
+        0x002C => "Display Data",
+        0x002D => "Display Status",
+        0x002E => "Stat Not Ready",
+        0x002F => "Stat Ready",
+        0x0030 => "Err Not a loadable character",
+        0x0031 => "Err Font data cannot be read",
+        0x0032 => "Cursor Position Report",
+        0x0033 => "Row",
+        0x0034 => "Column",
+        0x0035 => "Rows",
+        0x0036 => "Columns",
+        0x0037 => "Cursor Pixel Positioning",
+        0x0038 => "Cursor Mode",
+        0x0039 => "Cursor Enable",
+        0x003A => "Cursor Blink",
+        0x003B => "Font Report",
+        0x003C => "Font Data",
+        0x003D => "Character Width",
+        0x003E => "Character Height",
+        0x003F => "Character Spacing Horizontal",
+        0x0040 => "Character Spacing Vertical",
+        0x0041 => "Unicode Character Set",
+        0x0042 => "Font 7-Segment",
+        0x0043 => "7-Segment Direct Map",
+        0x0044 => "Font 14-Segment",
+        0x0045 => "14-Segment Direct Map",
+        0x0046 => "Display Brightness",
+        0x0047 => "Display Contrast",
+        0x0048 => "Character Attribute",
+        0x0049 => "Attribute Readback",
+        0x004A => "Attribute Data",
+        0x004B => "Char Attr Enhance",
+        0x004C => "Char Attr Underline",
+        0x004D => "Char Attr Blink",
+        0x0080 => "Bitmap Size X",
+        0x0081 => "Bitmap Size Y",
+        0x0082 => "Max Blit Size",
+        0x0083 => "Bit Depth Format",
+        0x0084 => "Display Orientation",
+        0x0085 => "Palette Report",
+        0x0086 => "Palette Data Size",
+        0x0087 => "Palette Data Offset",
+        0x0088 => "Palette Data",
+        0x008A => "Blit Report",
+        0x008B => "Blit Rectangle X1",
+        0x008C => "Blit Rectangle Y1",
+        0x008D => "Blit Rectangle X2",
+        0x008E => "Blit Rectangle Y2",
+        0x008F => "Blit Data",
+        0x0090 => "Soft Button",
+        0x0091 => "Soft Button ID",
+        0x0092 => "Soft Button Side",
+        0x0093 => "Soft Button Offset 1",
+        0x0094 => "Soft Button Offset 2",
+        0x0095 => "Soft Button Report",
+    ];
+    // Data fields combined with a modifier in the upper four bits of the
+    // usage ID, like the maximum of a data field, are not listed
+    0x0020, "Sensors" => named[
+        0x0001 => "Sensor",
+        0x0010 => "Biometric",
+        0x0011 => "Biometric: Human Presence",
+        0x0012 => "Biometric: Human Proximity",
+        0x0013 => "Biometric: Human Touch",
+        0x0014 => "Biometric: Blood Pressure",
+        0x0015 => "Biometric: Body Temperature",
+        0x0016 => "Biometric: Heart Rate",
+        0x0017 => "Biometric: Heart Rate Variability",
+        0x0018 => "Biometric: Peripheral Oxygen Saturation",
+        0x0019 => "Biometric: Respiratory Rate",
+        0x0020 => "Electrical",
+        0x0021 => "Electrical: Capacitance",
+        0x0022 => "Electrical: Current",
+        0x0023 => "Electrical: Power",
+        0x0024 => "Electrical: Inductance",
+        0x0025 => "Electrical: Resistance",
+        0x0026 => "Electrical: Voltage",
+        0x0027 => "Electrical: Potentiometer",
+        0x0028 => "Electrical: Frequency",
+        0x0029 => "Electrical: Period",
+        0x0030 => "Environmental",
+        0x0031 => "Environmental: Atmospheric Pressure",
+        0x0032 => "Environmental: Humidity",
+        0x0033 => "Environmental: Temperature",
+        0x0034 => "Environmental: Wind Direction",
+        0x0035 => "Environmental: Wind Speed",
+        0x0036 => "Environmental: Air Quality",
+        0x0037 => "Environmental: Heat Index",
+        0x0038 => "Environmental: Surface Temperature",
+        0x0039 => "Environmental: Volatile Organic Compounds",
+        0x003A => "Environmental: Object Presence",
+        0x003B => "Environmental: Object Proximity",
+        0x0040 => "Light",
+        0x0041 => "Light: Ambient Light",
+        0x0042 => "Light: Consumer Infrared",
+        0x0043 => "Light: Infrared Light",
+        0x0044 => "Light: Visible Light",
+        0x0045 => "Light: Ultraviolet Light",
+        0x0050 => "Location",
+        0x0051 => "Location: Broadcast",
+        0x0052 => "Location: Dead Reckoning",
+        0x0053 => "Location: GPS (Global Positioning System)",
+        0x0054 => "Location: Lookup",
+        0x0055 => "Location: Other",
+        0x0056 => "Location: Static",
+        0x0057 => "Location: Triangulation",
+        0x0060 => "Mechanical",
+        0x0061 => "Mechanical: Boolean Switch",
+        0x0062 => "Mechanical: Boolean Switch Array",
+        0x0063 => "Mechanical: Multivalue Switch",
+        0x0064 => "Mechanical: Force",
+        0x0065 => "Mechanical: Pressure",
+        0x0066 => "Mechanical: Strain",
+        0x0067 => "Mechanical: Weight",
+        0x0068 => "Mechanical: Haptic Vibrator",
+        0x0069 => "Mechanical: Hall Effect Switch",
+        0x0070 => "Motion",
+        0x0071 => "Motion: Accelerometer 1D",
+        0x0072 => "Motion: Accelerometer 2D",
+        0x0073 => "Motion: Accelerometer 3D",
+        0x0074 => "Motion: Gyrometer 1D",
+        0x0075 => "Motion: Gyrometer 2D",
+        0x0076 => "Motion: Gyrometer 3D",
+        0x0077 => "Motion: Motion Detector",
+        0x0078 => "Motion: Speedometer",
+        0x0079 => "Motion: Accelerometer",
+        0x007A => "Motion: Gyrometer",
+        0x007B => "Motion: Gravity Vector",
+        0x007C => "Motion: Linear Accelerometer",
+        0x0080 => "Orientation",
+        0x0081 => "Orientation: Compass 1D",
+        0x0082 => "Orientation: Compass 2D",
+        0x0083 => "Orientation: Compass 3D",
+        0x0084 => "Orientation: Inclinometer 1D",
+        0x0085 => "Orientation: Inclinometer 2D",
+        0x0086 => "Orientation: Inclinometer 3D",
+        0x0087 => "Orientation: Distance 1D",
+        0x0088 => "Orientation: Distance 2D",
+        0x0089 => "Orientation: Distance 3D",
+        0x008A => "Orientation: Device Orientation",
+        0x008B => "Orientation: Compass",
+        0x008C => "Orientation: Inclinometer",
+        0x008D => "Orientation: Distance",
+        0x008E => "Orientation: Relative Orientation",
+        0x008F => "Orientation: Simple Orientation",
+        0x0090 => "Scanner",
+        0x0091 => "Scanner: Barcode",
+        0x0092 => "Scanner: RFID",
+        0x0093 => "Scanner: NFC",
+        0x00A0 => "Time",
+        0x00A1 => "Time: Alarm Timer",
+        0x00A2 => "Time: Real Time Clock",
+        0x00B0 => "Personal Activity",
+        0x00B1 => "Personal Activity: Activity Detection",
+        0x00B2 => "Personal Activity: Device Position",
+        0x00B3 => "Personal Activity: Floor Tracker",
+        0x00B4 => "Personal Activity: Pedometer",
+        0x00B5 => "Personal Activity: Step Detection",
+        0x00C0 => "Orientation Extended",
+        0x00C1 => "Orientation Extended: Geomagnetic Orientation",
+        0x00C2 => "Orientation Extended: Magnetometer",
+        0x00D0 => "Gesture",
+        0x00D1 => "Gesture: Chassis Flip Gesture",
+        0x00D2 => "Gesture: Hinge Fold Gesture",
+        0x00E0 => "Other",
+        0x00E1 => "Other: Custom",
+        0x00E2 => "Other: Generic",
+        0x00E3 => "Other: Generic Enumerator",
+        0x00E4 => "Other: Hinge Angle",
+        0x0200 => "Event",
+        0x0201 => "Event: Sensor State",
+        0x0202 => "Event: Sensor Event",
+        0x0300 => "Property",
+        0x0301 => "Property: Friendly Name",
+        0x0302 => "Property: Persistent Unique ID",
+        0x0303 => "Property: Sensor Status",
+        0x0304 => "Property: Minimum Report Interval",
+        0x0305 => "Property: Sensor Manufacturer",
+        0x0306 => "Property: Sensor Model",
+        0x0307 => "Property: Sensor Serial Number",
+        0x0308 => "Property: Sensor Description",
+        0x0309 => "Property: Sensor Connection Type",
+        0x030A => "Property: Sensor Device Path",
+        0x030B => "Property: Hardware Revision",
+        0x030C => "Property: Firmware Version",
+        0x030D => "Property: Release Date",
+        0x030E => "Property: Report Interval",
+        0x030F => "Property: Change Sensitivity Absolute",
+        0x0310 => "Property: Change Sensitivity Percent of Range",
+        0x0311 => "Property: Change Sensitivity Percent Relative",
+        0x0312 => "Property: Accuracy",
+        0x0313 => "Property: Resolution",
+        0x0314 => "Property: Maximum",
+        0x0315 => "Property: Minimum",
+        0x0316 => "Property: Reporting State",
+        0x0317 => "Property: Sampling Rate",
+        0x0318 => "Property: Response Curve",
+        0x0319 => "Property: Power State",
+        0x031A => "Property: Maximum FIFO Events",
+        0x031B => "Property: Report Latency",
+        0x031C => "Property: Flush FIFO Events",
+        0x031D => "Property: Maximum Power Consumption",
+        0x031E => "Property: Is Primary",
+        0x031F => "Property: Human Presence Detection Type",
+        0x0400 => "Data Field: Location",
+        0x0402 => "Data Field: Altitude Antenna Sea Level",
+        0x0403 => "Data Field: Differential Reference Station ID",
+        0x0404 => "Data Field: Altitude Ellipsoid Error",
+        0x0405 => "Data Field: Altitude Ellipsoid",
+        0x0406 => "Data Field: Altitude Sea Level Error",
+        0x0407 => "Data Field: Altitude Sea Level",
+        0x0408 => "Data Field: Differential GPS Data Age",
+        0x0409 => "Data Field: Error Radius",
+        0x040A => "Data Field: Fix Quality",
+        0x040B => "Data Field: Fix Type",
+        0x040C => "Data Field: Geoidal Separation",
+        0x040D => "Data Field: GPS Operation Mode",
+        0x040E => "Data Field: GPS Selection Mode",
+        0x040F => "Data Field: GPS Status",
+        0x0410 => "Data Field: Position Dilution of Precision",
+        0x0411 => "Data Field: Horizontal Dilution of Precision",
+        0x0412 => "Data Field: Vertical Dilution of Precision",
+        0x0413 => "Data Field: Latitude",
+        0x0414 => "Data Field: Longitude",
+        0x0415 => "Data Field: True Heading",
+        0x0416 => "Data Field: Magnetic Heading",
+        0x0417 => "Data Field: Magnetic Variation",
+        0x0418 => "Data Field: Speed",
+        0x0419 => "Data Field: Satellites in View",
+        0x041A => "Data Field: Satellites in View Azimuth",
+        0x041B => "Data Field: Satellites in View Elevation",
+        0x041C => "Data Field: Satellites in View IDs",
+        0x041D => "Data Field: Satellites in View PRNs",
+        0x041E => "Data Field: Satellites in View S/N Ratios",
+        0x041F => "Data Field: Satellites Used Count",
+        0x0420 => "Data Field: Satellites Used PRNs",
+        0x0421 => "Data Field: NMEA Sentence",
+        0x0422 => "Data Field: Address Line 1",
+        0x0423 => "Data Field: Address Line 2",
+        0x0424 => "Data Field: City",
+        0x0425 => "Data Field: State or Province",
+        0x0426 => "Data Field: Country or Region",
+        0x0427 => "Data Field: Postal Code",
+        0x042A => "Property: Location",
+        0x042B => "Property: Location Desired Accuracy",
+        0x0430 => "Data Field: Environmental",
+        0x0431 => "Data Field: Atmospheric Pressure",
+        0x0433 => "Data Field: Relative Humidity",
+        0x0434 => "Data Field: Temperature",
+        0x0435 => "Data Field: Wind Direction",
+        0x0436 => "Data Field: Wind Speed",
+        0x0437 => "Data Field: Air Quality Index",
+        0x0438 => "Data Field: Equivalent CO2",
+        0x0439 => "Data Field: Volatile Organic Compound Concentration",
+        0x043A => "Data Field: Object Presence",
+        0x043B => "Data Field: Object Proximity Range",
+        0x043C => "Data Field: Object Proximity Out of Range",
+        0x0440 => "Property: Environmental",
+        0x0441 => "Property: Reference Pressure",
+        0x0450 => "Data Field: Motion",
+        0x0451 => "Data Field: Motion State",
+        0x0452 => "Data Field: Acceleration",
+        0x0453 => "Data Field: Acceleration Axis X",
+        0x0454 => "Data Field: Acceleration Axis Y",
+        0x0455 => "Data Field: Acceleration Axis Z",
+        0x0456 => "Data Field: Angular Velocity",
+        0x0457 => "Data Field: Angular Velocity about X Axis",
+        0x0458 => "Data Field: Angular Velocity about Y Axis",
+        0x0459 => "Data Field: Angular Velocity about Z Axis",
+        0x045A => "Data Field: Angular Position",
+        0x045B => "Data Field: Angular Position about X Axis",
+        0x045C => "Data Field: Angular Position about Y Axis",
+        0x045D => "Data Field: Angular Position about Z Axis",
+        0x045E => "Data Field: Motion Speed",
+        0x045F => "Data Field: Motion Intensity",
+        0x0470 => "Data Field: Orientation",
+        0x0471 => "Data Field: Heading",
+        0x0472 => "Data Field: Heading X Axis",
+        0x0473 => "Data Field: Heading Y Axis",
+        0x0474 => "Data Field: Heading Z Axis",
+        0x0475 => "Data Field: Heading Compensated Magnetic North",
+        0x0476 => "Data Field: Heading Compensated True North",
+        0x0477 => "Data Field: Heading Magnetic North",
+        0x0478 => "Data Field: Heading True North",
+        0x0479 => "Data Field: Distance",
+        0x047A => "Data Field: Distance X Axis",
+        0x047B => "Data Field: Distance Y Axis",
+        0x047C => "Data Field: Distance Z Axis",
+        0x047D => "Data Field: Distance Out-of-Range",
+        0x047E => "Data Field: Tilt",
+        0x047F => "Data Field: Tilt X Axis",
+        0x0480 => "Data Field: Tilt Y Axis",
+        0x0481 => "Data Field: Tilt Z Axis",
+        0x0482 => "Data Field: Rotation Matrix",
+        0x0483 => "Data Field: Quaternion",
+        0x0484 => "Data Field: Magnetic Flux",
+        0x0485 => "Data Field: Magnetic Flux X Axis",
+        0x0486 => "Data Field: Magnetic Flux Y Axis",
+        0x0487 => "Data Field: Magnetic Flux Z Axis",
+        0x0488 => "Data Field: Magnetometer Accuracy",
+        0x0489 => "Data Field: Simple Orientation Direction",
+        0x0490 => "Data Field: Mechanical",
+        0x0491 => "Data Field: Boolean Switch State",
+        0x0492 => "Data Field: Boolean Switch Array States",
+        0x0493 => "Data Field: Multivalue Switch Value",
+        0x0494 => "Data Field: Force",
+        0x0495 => "Data Field: Absolute Pressure",
+        0x0496 => "Data Field: Gauge Pressure",
+        0x0497 => "Data Field: Strain",
+        0x0498 => "Data Field: Weight",
+        0x04A0 => "Property: Mechanical",
+        0x04A1 => "Property: Vibration State",
+        0x04A2 => "Property: Forward Vibration Speed",
+        0x04A3 => "Property: Backward Vibration Speed",
+        0x04B0 => "Data Field: Biometric",
+        0x04B1 => "Data Field: Human Presence",
+        0x04B2 => "Data Field: Human Proximity Range",
+        0x04B3 => "Data Field: Human Proximity Out of Range",
+        0x04B4 => "Data Field: Human Touch State",
+        0x04B5 => "Data Field: Blood Pressure",
+        0x04B6 => "Data Field: Blood Pressure Diastolic",
+        0x04B7 => "Data Field: Blood Pressure Systolic",
+        0x04B8 => "Data Field: Heart Rate",
+        0x04B9 => "Data Field: Resting Heart Rate",
+        0x04BA => "Data Field: Heartbeat Interval",
+        0x04BB => "Data Field: Respiratory Rate",
+        0x04BC => "Data Field: SpO2",
+        0x04BD => "Data Field: Human Attention Detected",
+        0x04BE => "Data Field: Human Head Azimuth",
+        0x04BF => "Data Field: Human Head Altitude",
+        0x04C0 => "Data Field: Human Head Roll",
+        0x04C1 => "Data Field: Human Head Pitch",
+        0x04C2 => "Data Field: Human Head Yaw",
+        0x04C3 => "Data Field: Human Correlation Id",
+        0x04D0 => "Data Field: Light",
+        0x04D1 => "Data Field: Illuminance",
+        0x04D2 => "Data Field: Color Temperature",
+        0x04D3 => "Data Field: Chromaticity",
+        0x04D4 => "Data Field: Chromaticity X",
+        0x04D5 => "Data Field: Chromaticity Y",
+        0x04D6 => "Data Field: Consumer IR Sentence Receive",
+        0x04D7 => "Data Field: Infrared Light",
+        0x04D8 => "Data Field: Red Light",
+        0x04D9 => "Data Field: Green Light",
+        0x04DA => "Data Field: Blue Light",
+        0x04DB => "Data Field: Ultraviolet A Light",
+        0x04DC => "Data Field: Ultraviolet B Light",
+        0x04DD => "Data Field: Ultraviolet Index",
+        0x04DE => "Data Field: Near Infrared Light",
+        0x04DF => "Property: Light",
+        0x04E0 => "Property: Consumer IR Sentence Send",
+        0x04E2 => "Property: Auto Brightness Preferred",
+        0x04E3 => "Property: Auto Color Preferred",
+        0x04F0 => "Data Field: Scanner",
+        0x04F1 => "Data Field: RFID Tag 40 Bit",
+        0x04F2 => "Data Field: NFC Sentence Receive",
+        0x04F8 => "Property: Scanner",
+        0x04F9 => "Property: NFC Sentence Send",
+        0x0500 => "Data Field: Electrical",
+        0x0501 => "Data Field: Capacitance",
+        0x0502 => "Data Field: Current",
+        0x0503 => "Data Field: Electrical Power",
+        0x0504 => "Data Field: Inductance",
+        0x0505 => "Data Field: Resistance",
+        0x0506 => "Data Field: Voltage",
+        0x0507 => "Data Field: Frequency",
+        0x0508 => "Data Field: Period",
+        0x0509 => "Data Field: Percent of Range",
+        0x0520 => "Data Field: Time",
+        0x0521 => "Data Field: Year",
+        0x0522 => "Data Field: Month",
+        0x0523 => "Data Field: Day",
+        0x0524 => "Data Field: Day of Week",
+        0x0525 => "Data Field: Hour",
+        0x0526 => "Data Field: Minute",
+        0x0527 => "Data Field: Second",
+        0x0528 => "Data Field: Millisecond",
+        0x0529 => "Data Field: Timestamp",
+        0x052A => "Data Field: Julian Day of Year",
+        0x052B => "Data Field: Time Since System Boot",
+        0x0530 => "Property: Time",
+        0x0531 => "Property: Time Zone Offset from UTC",
+        0x0532 => "Property: Time Zone Name",
+        0x0533 => "Property: Daylight Savings Time Observed",
+        0x0534 => "Property: Time Trim Adjustment",
+        0x0535 => "Property: Arm Alarm",
+        0x0540 => "Data Field: Custom",
+        0x0541 => "Data Field: Custom Usage",
+        0x0542 => "Data Field: Custom Boolean Array",
+        0x0543 => "Data Field: Custom Value",
+        0x0544 => "Data Field: Custom Value 1",
+        0x0545 => "Data Field: Custom Value 2",
+        0x0546 => "Data Field: Custom Value 3",
+        0x0547 => "Data Field: Custom Value 4",
+        0x0548 => "Data Field: Custom Value 5",
+        0x0549 => "Data Field: Custom Value 6",
+        0x054A => "Data Field: Custom Value 7",
+        0x054B => "Data Field: Custom Value 8",
+        0x054C => "Data Field: Custom Value 9",
+        0x054D => "Data Field: Custom Value 10",
+        0x054E => "Data Field: Custom Value 11",
+        0x054F => "Data Field: Custom Value 12",
+        0x0550 => "Data Field: Custom Value 13",
+        0x0551 => "Data Field: Custom Value 14",
+        0x0552 => "Data Field: Custom Value 15",
+        0x0553 => "Data Field: Custom Value 16",
+        0x0554 => "Data Field: Custom Value 17",
+        0x0555 => "Data Field: Custom Value 18",
+        0x0556 => "Data Field: Custom Value 19",
+        0x0557 => "Data Field: Custom Value 20",
+        0x0558 => "Data Field: Custom Value 21",
+        0x0559 => "Data Field: Custom Value 22",
+        0x055A => "Data Field: Custom Value 23",
+        0x055B => "Data Field: Custom Value 24",
+        0x055C => "Data Field: Custom Value 25",
+        0x055D => "Data Field: Custom Value 26",
+        0x055E => "Data Field: Custom Value 27",
+        0x055F => "Data Field: Custom Value 28",
+        0x0560 => "Data Field: Generic",
+        0x0561 => "Data Field: Generic GUID or PROPERTYKEY",
+        0x0562 => "Data Field: Generic Category GUID",
+        0x0563 => "Data Field: Generic Type GUID",
+        0x0564 => "Data Field: Generic Event PROPERTYKEY",
+        0x0565 => "Data Field: Generic Property PROPERTYKEY",
+        0x0566 => "Data Field: Generic Data Field PROPERTYKEY",
+        0x0567 => "Data Field: Generic Event",
+        0x0568 => "Data Field: Generic Property",
+        0x0569 => "Data Field: Generic Data Field",
+        0x056A => "Data Field: Enumerator Table Row Index",
+        0x056B => "Data Field: Enumerator Table Row Count",
+        0x056C => "Data Field: Generic GUID or PROPERTYKEY Kind",
+        0x056D => "Data Field: Generic GUID",
+        0x056E => "Data Field: Generic PROPERTYKEY",
+        0x056F => "Data Field: Generic Top Level Collection ID",
+        0x0570 => "Data Field: Generic Report ID",
+        0x0571 => "Data Field: Generic Report Item Position Index",
+        0x0572 => "Data Field: Generic Firmware VARTYPE",
+        0x0573 => "Data Field: Generic Unit of Measure",
+        0x0574 => "Data Field: Generic Unit Exponent",
+        0x0575 => "Data Field: Generic Report Size",
+        0x0576 => "Data Field: Generic Report Count",
+        0x0580 => "Property: Generic",
+        0x0581 => "Property: Enumerator Table Row Index",
+        0x0582 => "Property: Enumerator Table Row Count",
+        0x0590 => "Data Field: Personal Activity",
+        0x0591 => "Data Field: Activity Type",
+        0x0592 => "Data Field: Activity State",
+        0x0593 => "Data Field: Device Position",
+        0x0594 => "Data Field: Step Count",
+        0x0595 => "Data Field: Step Count Reset",
+        0x0596 => "Data Field: Step Duration",
+        0x0597 => "Data Field: Step Type",
+        0x05A0 => "Property: Minimum Activity Detection Interval",
+        0x05A1 => "Property: Supported Activity Types",
+        0x05A2 => "Property: Subscribed Activity Types",
+        0x05A3 => "Property: Supported Step Types",
+        0x05A4 => "Property: Subscribed Step Types",
+        0x05A5 => "Property: Floor Height",
+        0x05D0 => "Data Field: Hinge",
+        0x05D1 => "Data Field: Hinge Angle",
+        0x05E0 => "Data Field: Gesture Sensor",
+        0x05E1 => "Data Field: Gesture State",
+        0x05E2 => "Data Field: Hinge Fold Initial Angle",
+        0x05E3 => "Data Field: Hinge Fold Final Angle",
+        0x05E4 => "Data Field: Hinge Fold Contributing Panel",
+        0x05E5 => "Data Field: Hinge Fold Type",
+        0x0800 => "Sensor State: Undefined",
+        0x0801 => "Sensor State: Ready",
+        0x0802 => "Sensor State: Not Available",
+        0x0803 => "Sensor State: No Data",
+        0x0804 => "Sensor State: Initializing",
+        0x0805 => "Sensor State: Access Denied",
+        0x0806 => "Sensor State: Error",
+        0x0810 => "Sensor Event: Unknown",
+        0x0811 => "Sensor Event: State Changed",
+        0x0812 => "Sensor Event: Property Changed",
+        0x0813 => "Sensor Event: Data Updated",
+        0x0814 => "Sensor Event: Poll Response",
+        0x0815 => "Sensor Event: Change Sensitivity",
+        0x0816 => "Sensor Event: Range Maximum Reached",
+        0x0817 => "Sensor Event: Range Minimum Reached",
+        0x0818 => "Sensor Event: High Threshold Cross Upward",
+        0x0819 => "Sensor Event: High Threshold Cross Downward",
+        0x081A => "Sensor Event: Low Threshold Cross Upward",
+        0x081B => "Sensor Event: Low Threshold Cross Downward",
+        0x081C => "Sensor Event: Zero Threshold Cross Upward",
+        0x081D => "Sensor Event: Zero Threshold Cross Downward",
+        0x081E => "Sensor Event: Period Exceeded",
+        0x081F => "Sensor Event: Frequency Exceeded",
+        0x0820 => "Sensor Event: Complex Trigger",
+        0x0830 => "Connection Type: PC Integrated",
+        0x0831 => "Connection Type: PC Attached",
+        0x0832 => "Connection Type: PC External",
+        0x0840 => "Reporting State: Report No Events",
+        0x0841 => "Reporting State: Report All Events",
+        0x0842 => "Reporting State: Report Threshold Events",
+        0x0843 => "Reporting State: Wake On No Events",
+        0x0844 => "Reporting State: Wake On All Events",
+        0x0845 => "Reporting State: Wake On Threshold Events",
+        0x0850 => "Power State: Undefined",
+        0x0851 => "Power State: D0 Full Power",
+        0x0852 => "Power State: D1 Low Power",
+        0x0853 => "Power State: D2 Standby Power with Wakeup",
+        0x0854 => "Power State: D3 Sleep with Wakeup",
+        0x0855 => "Power State: D4 Power Off",
+        0x0860 => "Accuracy: Default",
+        0x0861 => "Accuracy: High",
+        0x0862 => "Accuracy: Medium",
+        0x0863 => "Accuracy: Low",
+        0x0870 => "Fix Quality: No Fix",
+        0x0871 => "Fix Quality: GPS",
+        0x0872 => "Fix Quality: DGPS",
+        0x0880 => "Fix Type: No Fix",
+        0x0881 => "Fix Type: GPS SPS Mode, Fix Valid",
+        0x0882 => "Fix Type: DGPS SPS Mode, Fix Valid",
+        0x0883 => "Fix Type: GPS PPS Mode, Fix Valid",
+        0x0884 => "Fix Type: Real Time Kinematic",
+        0x0885 => "Fix Type: Float RTK",
+        0x0886 => "Fix Type: Estimated (dead reckoned)",
+        0x0887 => "Fix Type: Manual Input Mode",
+        0x0888 => "Fix Type: Simulator Mode",
+        0x0890 => "GPS Operation Mode: Manual",
+        0x0891 => "GPS Operation Mode: Automatic",
+        0x08A0 => "GPS Selection Mode: Autonomous",
+        0x08A1 => "GPS Selection Mode: DGPS",
+        0x08A2 => "GPS Selection Mode: Estimated (dead reckoned)",
+        0x08A3 => "GPS Selection Mode: Manual Input",
+        0x08A4 => "GPS Selection Mode: Simulator",
+        0x08A5 => "GPS Selection Mode: Data Not Valid",
+        0x08B0 => "GPS Status Data: Valid",
+        0x08B1 => "GPS Status Data: Not Valid",
+        0x08C0 => "Day of Week: Sunday",
+        0x08C1 => "Day of Week: Monday",
+        0x08C2 => "Day of Week: Tuesday",
+        0x08C3 => "Day of Week: Wednesday",
+        0x08C4 => "Day of Week: Thursday",
+        0x08C5 => "Day of Week: Friday",
+        0x08C6 => "Day of Week: Saturday",
+        0x08D0 => "Kind: Category",
+        0x08D1 => "Kind: Type",
+        0x08D2 => "Kind: Event",
+        0x08D3 => "Kind: Property",
+        0x08D4 => "Kind: Data Field",
+        0x08E0 => "Magnetometer Accuracy: Low",
+        0x08E1 => "Magnetometer Accuracy: Medium",
+        0x08E2 => "Magnetometer Accuracy: High",
+        0x08F0 => "Simple Orientation Direction: Not Rotated",
+        0x08F1 => "Simple Orientation Direction: Rotated 90 Degrees CCW",
+        0x08F2 => "Simple Orientation Direction: Rotated 180 Degrees CCW",
+        0x08F3 => "Simple Orientation Direction: Rotated 270 Degrees CCW",
+        0x08F4 => "Simple Orientation Direction: Face Up",
+        0x08F5 => "Simple Orientation Direction: Face Down",
+        0x0900 => "VT_NULL",
+        0x0901 => "VT_BOOL",
+        0x0902 => "VT_UI1",
+        0x0903 => "VT_I1",
+        0x0904 => "VT_UI2",
+        0x0905 => "VT_I2",
+        0x0906 => "VT_UI4",
+        0x0907 => "VT_I4",
+        0x0908 => "VT_UI8",
+        0x0909 => "VT_I8",
+        0x090A => "VT_R4",
+        0x090B => "VT_R8",
+        0x090C => "VT_WSTR",
+        0x090D => "VT_STR",
+        0x090E => "VT_CLSID",
+        0x090F => "VT_VECTOR VT_UI1",
+        0x0910 => "VT_F16E0",
+        0x0911 => "VT_F16E1",
+        0x0912 => "VT_F16E2",
+        0x0913 => "VT_F16E3",
+        0x0914 => "VT_F16E4",
+        0x0915 => "VT_F16E5",
+        0x0916 => "VT_F16E6",
+        0x0917 => "VT_F16E7",
+        0x0918 => "VT_F16E8",
+        0x0919 => "VT_F16E9",
+        0x091A => "VT_F16EA",
+        0x091B => "VT_F16EB",
+        0x091C => "VT_F16EC",
+        0x091D => "VT_F16ED",
+        0x091E => "VT_F16EE",
+        0x091F => "VT_F16EF",
+        0x0920 => "VT_F32E0",
+        0x0921 => "VT_F32E1",
+        0x0922 => "VT_F32E2",
+        0x0923 => "VT_F32E3",
+        0x0924 => "VT_F32E4",
+        0x0925 => "VT_F32E5",
+        0x0926 => "VT_F32E6",
+        0x0927 => "VT_F32E7",
+        0x0928 => "VT_F32E8",
+        0x0929 => "VT_F32E9",
+        0x092A => "VT_F32EA",
+        0x092B => "VT_F32EB",
+        0x092C => "VT_F32EC",
+        0x092D => "VT_F32ED",
+        0x092E => "VT_F32EE",
+        0x092F => "VT_F32EF",
+        0x0930 => "Activity Type: Unknown",
+        0x0931 => "Activity Type: Stationary",
+        0x0932 => "Activity Type: Fidgeting",
+        0x0933 => "Activity Type: Walking",
+        0x0934 => "Activity Type: Running",
+        0x0935 => "Activity Type: In Vehicle",
+        0x0936 => "Activity Type: Biking",
+        0x0937 => "Activity Type: Idle",
+        0x0940 => "Unit: Not Specified",
+        0x0941 => "Unit: Lux",
+        0x0942 => "Unit: Degrees Kelvin",
+        0x0943 => "Unit: Degrees Celsius",
+        0x0944 => "Unit: Pascal",
+        0x0945 => "Unit: Newton",
+        0x0946 => "Unit: Meters/Second",
+        0x0947 => "Unit: Kilogram",
+        0x0948 => "Unit: Meter",
+        0x0949 => "Unit: Meters/Second/Second",
+        0x094A => "Unit: Farad",
+        0x094B => "Unit: Ampere",
+        0x094C => "Unit: Watt",
+        0x094D => "Unit: Henry",
+        0x094E => "Unit: Ohm",
+        0x094F => "Unit: Volt",
+        0x0950 => "Unit: Hertz",
+        0x0951 => "Unit: Bar",
+        0x0952 => "Unit: Degrees Anti-clockwise",
+        0x0953 => "Unit: Degrees Clockwise",
+        0x0954 => "Unit: Degrees",
+        0x0955 => "Unit: Degrees/Second",
+        0x0956 => "Unit: Degrees/Second/Second",
+        0x0957 => "Unit: Knot",
+        0x0958 => "Unit: Percent",
+        0x0959 => "Unit: Second",
+        0x095A => "Unit: Millisecond",
+        0x095B => "Unit: G",
+        0x095C => "Unit: Bytes",
+        0x095D => "Unit: Milligauss",
+        0x095E => "Unit: Bits",
+        0x0960 => "Activity State: No State Change",
+        0x0961 => "Activity State: Start Activity",
+        0x0962 => "Activity State: End Activity",
+        0x0970 => "Exponent 0",
+        0x0971 => "Exponent 1",
+        0x0972 => "Exponent 2",
+        0x0973 => "Exponent 3",
+        0x0974 => "Exponent 4",
+        0x0975 => "Exponent 5",
+        0x0976 => "Exponent 6",
+        0x0977 => "Exponent 7",
+        0x0978 => "Exponent 8",
+        0x0979 => "Exponent 9",
+        0x097A => "Exponent A",
+        0x097B => "Exponent B",
+        0x097C => "Exponent C",
+        0x097D => "Exponent D",
+        0x097E => "Exponent E",
+        0x097F => "Exponent F",
+        0x0980 => "Device Position: Unknown",
+        0x0981 => "Device Position: Unchanged",
+        0x0982 => "Device Position: On Desk",
+        0x0983 => "Device Position: In Hand",
+        0x0984 => "Device Position: Moving in Bag",
+        0x0985 => "Device Position: Stationary in Bag",
+        0x0990 => "Step Type: Unknown",
+        0x0991 => "Step Type: Walking",
+        0x0992 => "Step Type: Running",
+        0x09A0 => "Gesture State: Unknown",
+        0x09A1 => "Gesture State: Started",
+        0x09A2 => "Gesture State: Completed",
+        0x09A3 => "Gesture State: Cancelled",
+        0x09B0 => "Hinge Fold Contributing Panel: Unknown",
+        0x09B1 => "Hinge Fold Contributing Panel: Panel 1",
+        0x09B2 => "Hinge Fold Contributing Panel: Panel 2",
+        0x09B3 => "Hinge Fold Contributing Panel: Both",
+        0x09C0 => "Hinge Fold Type: Unknown",
+        0x09C1 => "Hinge Fold Type: Increasing",
+        0x09C2 => "Hinge Fold Type: Decreasing",
+        0x09D0 => "Human Presence Detection Type: Vendor-Defined Non-Biometric",
+        0x09D1 => "Human Presence Detection Type: Vendor-Defined Biometric",
+        0x09D2 => "Human Presence Detection Type: Facial Biometric",
+        0x09D3 => "Human Presence Detection Type: Audio Biometric",
+    ];
+    0x0040, "Medical Instrument" => named[
+        0x0001 => "Medical Ultrasound",
+        0x0020 => "VCR/Acquisition",
+        0x0021 => "Freeze/Thaw",
+        0x0022 => "Clip Store",
+        0x0023 => "Update",
+        0x0024 => "Next",
+        0x0025 => "Save",
+        0x0026 => "Print",
+        0x0027 => "Microphone Enable",
+        0x0040 => "Cine",
+        0x0041 => "Transmit Power",
+        0x0042 => "Volume",
+        0x0043 => "Focus",
+        0x0044 => "Depth",
+        0x0060 => "Soft Step - Primary",
+        0x0061 => "Soft Step - Secondary",
+        0x0070 => "Depth Gain Compensation",
+        0x0080 => "Zoom Select",
+        0x0081 => "Zoom Adjust",
+        0x0082 => "Spectral Doppler Mode Select",
+        0x0083 => "Spectral Doppler Adjust",
+        0x0084 => "Color Doppler Mode Select",
+        0x0085 => "Color Doppler Adjust",
+        0x0086 => "Motion Mode Select",
+        0x0087 => "Motion Mode Adjust",
+        0x0088 => "2-D Mode Select",
+        0x0089 => "2-D Mode Adjust",
+        0x00A0 => "Soft Control Select",
+        0x00A1 => "Soft Control Adjust",
+    ];
+    0x0041, "Braille Display" => named[
+        0x0001 => "Braille Display",
+        0x0002 => "Braille Row",
+        0x0003 => "8 Dot Braille Cell",
+        0x0004 => "6 Dot Braille Cell",
+        0x0005 => "Number of Braille Cells",
+        0x0006 => "Screen Reader Control",
+        0x0007 => "Screen Reader Identifier",
+        0x00FA => "Router Set 1",
+        0x00FB => "Router Set 2",
+        0x00FC => "Router Set 3",
+        0x0100 => "Router Key",
+        0x0101 => "Row Router Key",
+        0x0200 => "Braille Buttons",
+        0x0201 => "Braille Keyboard Dot 1",
+        0x0202 => "Braille Keyboard Dot 2",
+        0x0203 => "Braille Keyboard Dot 3",
+        0x0204 => "Braille Keyboard Dot 4",
+        0x0205 => "Braille Keyboard Dot 5",
+        0x0206 => "Braille Keyboard Dot 6",
+        0x0207 => "Braille Keyboard Dot 7",
+        0x0208 => "Braille Keyboard Dot 8",
+        0x0209 => "Braille Keyboard Space",
+        0x020A => "Braille Keyboard Left Space",
+        0x020B => "Braille Keyboard Right Space",
+        0x020C => "Braille Face Controls",
+        0x020D => "Braille Left Controls",
+        0x020E => "Braille Right Controls",
+        0x020F => "Braille Top Controls",
+        0x0210 => "Braille Joystick Center",
+        0x0211 => "Braille Joystick Up",
+        0x0212 => "Braille Joystick Down",
+        0x0213 => "Braille Joystick Left",
+        0x0214 => "Braille Joystick Right",
+        0x0215 => "Braille D-Pad Center",
+        0x0216 => "Braille D-Pad Up",
+        0x0217 => "Braille D-Pad Down",
+        0x0218 => "Braille D-Pad Left",
+        0x0219 => "Braille D-Pad Right",
+        0x021A => "Braille Pan Left",
+        0x021B => "Braille Pan Right",
+        0x021C => "Braille Rocker Up",
+        0x021D => "Braille Rocker Down",
+        0x021E => "Braille Rocker Press",
+    ];
+    0x0059, "Lighting And Illumination" => named[
+        0x0001 => "LampArray",
+        0x0002 => "LampArrayAttributesReport",
+        0x0003 => "LampCount",
+        0x0004 => "BoundingBoxWidthInMicrometers",
+        0x0005 => "BoundingBoxHeightInMicrometers",
+        0x0006 => "BoundingBoxDepthInMicrometers",
+        0x0007 => "LampArrayKind",
+        0x0008 => "MinUpdateIntervalInMicroseconds",
+        0x0020 => "LampAttributesRequestReport",
+        0x0021 => "LampId",
+        0x0022 => "LampAttributesResponseReport",
+        0x0023 => "PositionXInMicrometers",
+        0x0024 => "PositionYInMicrometers",
+        0x0025 => "PositionZInMicrometers",
+        0x0026 => "LampPurposes",
+        0x0027 => "UpdateLatencyInMicroseconds",
+        0x0028 => "RedLevelCount",
+        0x0029 => "GreenLevelCount",
+        0x002A => "BlueLevelCount",
+        0x002B => "IntensityLevelCount",
+        0x002C => "IsProgrammable",
+        0x002D => "InputBinding",
+        0x0050 => "LampMultiUpdateReport",
+        0x0051 => "RedUpdateChannel",
+        0x0052 => "GreenUpdateChannel",
+        0x0053 => "BlueUpdateChannel",
+        0x0054 => "IntensityUpdateChannel",
+        0x0055 => "LampUpdateFlags",
+        0x0060 => "LampRangeUpdateReport",
+        0x0061 => "LampIdStart",
+        0x0062 => "LampIdEnd",
+        0x0070 => "LampArrayControlReport",
+        0x0071 => "AutonomousMode",
+    ];
+    0x0080, "Monitor" => named[
+        0x0001 => "Monitor Control",
+        0x0002 => "EDID Information",
+        0x0003 => "VDIF Information",
+        0x0004 => "VESA Version",
+    ];
+    0x0081, "Monitor Enumerated" => numbered("Enum");
+    0x0082, "VESA Virtual Controls" => named[
+        0x0001 => "Degauss",
+        0x0010 => "Brightness",
+        0x0012 => "Contrast",
+        0x0016 => "Red Video Gain",
+        0x0018 => "Green Video Gain",
+        0x001A => "Blue Video Gain",
+        0x001C => "Focus",
+        0x0020 => "Horizontal Position",
+        0x0022 => "Horizontal Size",
+        0x0024 => "Horizontal Pincushion",
+        0x0026 => "Horizontal Pincushion Balance",
+        0x0028 => "Horizontal Misconvergence",
+        0x002A => "Horizontal Linearity",
+        0x002C => "Horizontal Linearity Balance",
+        0x0030 => "Vertical Position",
+        0x0032 => "Vertical Size",
+        0x0034 => "Vertical Pincushion",
+        0x0036 => "Vertical Pincushion Balance",
+        0x0038 => "Vertical Misconvergence",
+        0x003A => "Vertical Linearity",
+        0x003C => "Vertical Linearity Balance",
+        0x0040 => "Parallelogram Distortion (Key Balance)",
+        0x0042 => "Trapezoidal Distortion (Key)",
+        0x0044 => "Tilt (Rotation)",
+        0x0046 => "Top Corner Distortion Control",
+        0x0048 => "Top Corner Distortion Balance",
+        0x004A => "Bottom Corner Distortion Control",
+        0x004C => "Bottom Corner Distortion Balance",
+        0x0056 => "Horizontal Moiré",
+        0x0058 => "Vertical Moiré",
+        0x005E => "Input Level Select",
+        0x0060 => "Input Source Select",
+        0x006C => "Red Video Black Level",
+        0x006E => "Green Video Black Level",
+        0x0070 => "Blue Video Black Level",
+        0x00A2 => "Auto Size Center",
+        0x00A4 => "Polarity Horizontal Synchronization",
+        0x00A6 => "Polarity Vertical Synchronization",
+        0x00A8 => "Synchronization Type",
+        0x00AA => "Screen Orientation",
+        0x00AC => "Horizontal Frequency",
+        0x00AE => "Vertical Frequency",
+        0x00B0 => "Settings",
+        0x00CA => "On Screen Display",
+        0x00D4 => "Stereo Mode",
+    ];
+    0x0084, "Power Device" => named[
+        0x0001 => "iName",
+        0x0002 => "Present Status",
+        0x0003 => "Changed Status",
+        0x0004 => "UPS",
+        0x0005 => "Power Supply",
+        0x0010 => "Battery System",
+        0x0011 => "Battery System Id",
+        0x0012 => "Battery",
+        0x0013 => "Battery Id",
+        0x0014 => "Charger",
+        0x0015 => "Charger Id",
+        0x0016 => "Power Converter",
+        0x0017 => "Power Converter Id",
+        0x0018 => "Outlet System",
+        0x0019 => "Outlet System Id",
+        0x001A => "Input",
+        0x001B => "Input Id",
+        0x001C => "Output",
+        0x001D => "Output Id",
+        0x001E => "Flow",
+        0x001F => "Flow Id",
+        0x0020 => "Outlet",
+        0x0021 => "Outlet Id",
+        0x0022 => "Gang",
+        0x0023 => "Gang Id",
+        0x0024 => "Power Summary",
+        0x0025 => "Power Summary Id",
+        0x0030 => "Voltage",
+        0x0031 => "Current",
+        0x0032 => "Frequency",
+        0x0033 => "Apparent Power",
+        0x0034 => "Active Power",
+        0x0035 => "Percent Load",
+        0x0036 => "Temperature",
+        0x0037 => "Humidity",
+        0x0038 => "Bad Count",
+        0x0040 => "Config Voltage",
+        0x0041 => "Config Current",
+        0x0042 => "Config Frequency",
+        0x0043 => "Config Apparent Power",
+        0x0044 => "Config Active Power",
+        0x0045 => "Config Percent Load",
+        0x0046 => "Config Temperature",
+        0x0047 => "Config Humidity",
+        0x0050 => "Switch On Control",
+        0x0051 => "Switch Off Control",
+        0x0052 => "Toggle Control",
+        0x0053 => "Low Voltage Transfer",
+        0x0054 => "High Voltage Transfer",
+        0x0055 => "Delay Before Reboot",
+        0x0056 => "Delay Before Startup",
+        0x0057 => "Delay Before Shutdown",
+        0x0058 => "Test",
+        0x0059 => "Module Reset",
+        0x005A => "Audible Alarm Control",
+        0x0060 => "Present",
+        0x0061 => "Good",
+        0x0062 => "Internal Failure",
+        0x0063 => "Voltage Out Of Range",
+        0x0064 => "Frequency Out Of Range",
+        0x0065 => "Overload",
+        0x0066 => "Over Charged",
+        0x0067 => "Over Temperature",
+        0x0068 => "Shutdown Requested",
+        0x0069 => "Shutdown Imminent",
+        0x006B => "Switch On/Off",
+        0x006C => "Switchable",
+        0x006D => "Used",
+        0x006E => "Boost",
+        0x006F => "Buck",
+        0x0070 => "Initialized",
+        0x0071 => "Tested",
+        0x0072 => "Awaiting Power",
+        0x0073 => "Communication Lost",
+        0x00FD => "iManufacturer",
+        0x00FE => "iProduct",
+        0x00FF => "iSerialNumber",
+    ];
+    0x0085, "Battery System" => named[
+        0x0001 => "Smart Battery Battery Mode",
+        0x0002 => "Smart Battery Battery Status",
+        0x0003 => "Smart Battery Alarm Warning",
+        0x0004 => "Smart Battery Charger Mode",
+        0x0005 => "Smart Battery Charger Status",
+        0x0006 => "Smart Battery Charger Spec Info",
+        0x0007 => "Smart Battery Selector State",
+        0x0008 => "Smart Battery Selector Presets",
+        0x0009 => "Smart Battery Selector Info",
+        0x0010 => "Optional Mfg Function 1",
+        0x0011 => "Optional Mfg Function 2",
+        0x0012 => "Optional Mfg Function 3",
+        0x0013 => "Optional Mfg Function 4",
+        0x0014 => "Optional Mfg Function 5",
+        0x0015 => "Connection To SM Bus",
+        0x0016 => "Output Connection",
+        0x0017 => "Charger Connection",
+        0x0018 => "Battery Insertion",
+        0x0019 => "Use Next",
+        0x001A => "OK To Use",
+        0x001B => "Battery Supported",
+        0x001C => "Selector Revision",
+        0x001D => "Charging Indicator",
+        0x0028 => "Manufacturer Access",
+        0x0029 => "Remaining Capacity Limit",
+        0x002A => "Remaining Time Limit",
+        0x002B => "At Rate",
+        0x002C => "Capacity Mode",
+        0x002D => "Broadcast To Charger",
+        0x002E => "Primary Battery",
+        0x002F => "Charge Controller",
+        0x0040 => "Terminate Charge",
+        0x0041 => "Terminate Discharge",
+        0x0042 => "Below Remaining Capacity Limit",
+        0x0043 => "Remaining Time Limit Expired",
+        0x0044 => "Charging",
+        0x0045 => "Discharging",
+        0x0046 => "Fully Charged",
+        0x0047 => "Fully Discharged",
+        0x0048 => "Conditioning Flag",
+        0x0049 => "At Rate OK",
+        0x004A => "Smart Battery Error Code",
+        0x004B => "Need Replacement",
+        0x0060 => "At Rate Time To Full",
+        0x0061 => "At Rate Time To Empty",
+        0x0062 => "Average Current",
+        0x0063 => "Max Error",
+        0x0064 => "Relative State Of Charge",
+        0x0065 => "Absolute State Of Charge",
+        0x0066 => "Remaining Capacity",
+        0x0067 => "Full Charge Capacity",
+        0x0068 => "Run Time To Empty",
+        0x0069 => "Average Time To Empty",
+        0x006A => "Average Time To Full",
+        0x006B => "Cycle Count",
+        0x0080 => "Battery Pack Model Level",
+        0x0081 => "Internal Charge Controller",
+        0x0082 => "Primary Battery Support",
+        0x0083 => "Design Capacity",
+        0x0084 => "Specification Info",
+        0x0085 => "Manufacture Date",
+        0x0086 => "Serial Number",
+        0x0087 => "iManufacturer Name",
+        0x0088 => "iDevice Name",
+        0x0089 => "iDevice Chemistry",
+        0x008A => "Manufacturer Data",
+        0x008B => "Rechargable",
+        0x008C => "Warning Capacity Limit",
+        0x008D => "Capacity Granularity 1",
+        0x008E => "Capacity Granularity 2",
+        0x008F => "iOEM Information",
+        0x00C0 => "Inhibit Charge",
+        0x00C1 => "Enable Polling",
+        0x00C2 => "Reset To Zero",
+        0x00D0 => "AC Present",
+        0x00D1 => "Battery Present",
+        0x00D2 => "Power Fail",
+        0x00D3 => "Alarm Inhibited",
+        0x00D4 => "Thermistor Under Range",
+        0x00D5 => "Thermistor Hot",
+        0x00D6 => "Thermistor Cold",
+        0x00D7 => "Thermistor Over Range",
+        0x00D8 => "Voltage Out Of Range",
+        0x00D9 => "Current Out Of Range",
+        0x00DA => "Current Not Regulated",
+        0x00DB => "Voltage Not Regulated",
+        0x00DC => "Master Mode",
+        0x00F0 => "Charger Selector Support",
+        0x00F1 => "Charger Spec",
+        0x00F2 => "Level 2",
+        0x00F3 => "Level 3",
+    ];
+    0x008C, "Barcode Scanner" => named[
+        0x0001 => "Barcode Badge Reader",
+        0x0002 => "Barcode Scanner",
+        0x0003 => "Dumb Bar Code Scanner",
+        0x0004 => "Cordless Scanner Base",
+        0x0005 => "Bar Code Scanner Cradle",
+        0x0010 => "Attribute Report",
+        0x0011 => "Settings Report",
+        0x0012 => "Scanned Data Report",
+        0x0013 => "Raw Scanned Data Report",
+        0x0014 => "Trigger Report",
+        0x0015 => "Status Report",
+        0x0016 => "UPC/EAN Control Report",
+        0x0017 => "EAN 2/3 Label Control Report",
+        0x0018 => "Code 39 Control Report",
+        0x0019 => "Interleaved 2 of 5 Control Report",
+        0x001A => "Standard 2 of 5 Control Report",
+        0x001B => "MSI Plessey Control Report",
+        0x001C => "Codabar Control Report",
+        0x001D => "Code 128 Control Report",
+        0x001E => "Misc 1D Control Report",
+        0x001F => "2D Control Report",
+        0x0030 => "Aiming/Pointer Mode",
+        0x0031 => "Bar Code Present Sensor",
+        0x0032 => "Class 1A Laser",
+        0x0033 => "Class 2 Laser",
+        0x0034 => "Heater Present",
+        0x0035 => "Contact Scanner",
+        0x0036 => "Electronic Article Surveillance Notification",
+        0x0037 => "Constant Electronic Article Surveillance",
+        0x0038 => "Error Indication",
+        0x0039 => "Fixed Beeper",
+        0x003A => "Good Decode Indication",
+        0x003B => "Hands Free Scanning",
+        0x003C => "Intrinsically Safe",
+        0x003D => "Klasse Eins Laser",
+        0x003E => "Long Range Scanner",
+        0x003F => "Mirror Speed Control",
+        0x0040 => "Not On File Indication",
+        0x0041 => "Programmable Beeper",
+        0x0042 => "Triggerless",
+        0x0043 => "Wand",
+        0x0044 => "Water Resistant",
+        0x0045 => "Multi-Range Scanner",
+        0x0046 => "Proximity Sensor",
+        0x004D => "Fragment Decoding",
+        0x004E => "Scanner Read Confidence",
+        0x004F => "Data Prefix",
+        0x0050 => "Prefix AIMI",
+        0x0051 => "Prefix None",
+        0x0052 => "Prefix Proprietary",
+        0x0055 => "Active Time",
+        0x0056 => "Aiming Laser Pattern",
+        0x0057 => "Bar Code Present",
+        0x0058 => "Beeper State",
+        0x0059 => "Laser On Time",
+        0x005A => "Laser State",
+        0x005B => "Lockout Time",
+        0x005C => "Motor State",
+        0x005D => "Motor Timeout",
+        0x005E => "Power On Reset Scanner",
+        0x005F => "Prevent Read of Barcodes",
+        0x0060 => "Initiate Barcode Read",
+        0x0061 => "Trigger State",
+        0x0062 => "Trigger Mode",
+        0x0063 => "Trigger Mode Blinking Laser On",
+        0x0064 => "Trigger Mode Continuous Laser On",
+        0x0065 => "Trigger Mode Laser on while Pulled",
+        0x0066 => "Trigger Mode Laser stays on after release",
+        0x006D => "Commit Parameters to NVM",
+        0x006E => "Parameter Scanning",
+        0x006F => "Parameters Changed",
+        0x0070 => "Set parameter default values",
+        0x0075 => "Scanner In Cradle",
+        0x0076 => "Scanner In Range",
+        0x007A => "Aim Duration",
+        0x007B => "Good Read Lamp Duration",
+        0x007C => "Good Read Lamp Intensity",
+        0x007D => "Good Read LED",
+        0x007E => "Good Read Tone Frequency",
+        0x007F => "Good Read Tone Length",
+        0x0080 => "Good Read Tone Volume",
+        0x0082 => "No Read Message",
+        0x0083 => "Not on File Volume",
+        0x0084 => "Powerup Beep",
+        0x0085 => "Sound Error Beep",
+        0x0086 => "Sound Good Read Beep",
+        0x0087 => "Sound Not On File Beep",
+        0x0088 => "Good Read When to Write",
+        0x0089 => "GRWTI After Decode",
+        0x008A => "GRWTI Beep/Lamp after transmit",
+        0x008B => "GRWTI No Beep/Lamp use at all",
+        0x0091 => "Bookland EAN",
+        0x0092 => "Convert EAN 8 to 13 Type",
+        0x0093 => "Convert UPC A to EAN-13",
+        0x0094 => "Convert UPC-E to A",
+        0x0095 => "EAN-13",
+        0x0096 => "EAN-8",
+        0x0097 => "EAN-99 128 Mandatory",
+        0x0098 => "EAN-99 P5/128 Optional",
+        0x0099 => "Enable EAN Two Label",
+        0x009A => "UPC/EAN",
+        0x009B => "UPC/EAN Coupon Code",
+        0x009C => "UPC/EAN Periodicals",
+        0x009D => "UPC-A",
+        0x009E => "UPC-A with 128 Mandatory",
+        0x009F => "UPC-A with 128 Optional",
+        0x00A0 => "UPC-A with P5 Optional",
+        0x00A1 => "UPC-E",
+        0x00A2 => "UPC-E1",
+        0x00A9 => "Periodical",
+        0x00AA => "Periodical Auto-Discriminate +2",
+        0x00AB => "Periodical Only Decode with +2",
+        0x00AC => "Periodical Ignore +2",
+        0x00AD => "Periodical Auto-Discriminate +5",
+        0x00AE => "Periodical Only Decode with +5",
+        0x00AF => "Periodical Ignore +5",
+        0x00B0 => "Check",
+        0x00B1 => "Check Disable Price",
+        0x00B2 => "Check Enable 4 digit Price",
+        0x00B3 => "Check Enable 5 digit Price",
+        0x00B4 => "Check Enable European 4 digit Price",
+        0x00B5 => "Check Enable European 5 digit Price",
+        0x00B7 => "EAN Two Label",
+        0x00B8 => "EAN Three Label",
+        0x00B9 => "EAN 8 Flag Digit 1",
+        0x00BA => "EAN 8 Flag Digit 2",
+        0x00BB => "EAN 8 Flag Digit 3",
+        0x00BC => "EAN 13 Flag Digit 1",
+        0x00BD => "EAN 13 Flag Digit 2",
+        0x00BE => "EAN 13 Flag Digit 3",
+        0x00BF => "Add EAN 2/3 Label Definition",
+        0x00C0 => "Clear all EAN 2/3 Label Definitions",
+        0x00C3 => "Codabar",
+        0x00C4 => "Code 128",
+        0x00C7 => "Code 39",
+        0x00C8 => "Code 93",
+        0x00C9 => "Full ASCII Conversion",
+        0x00CA => "Interleaved 2 of 5",
+        0x00CB => "Italian Pharmacy Code",
+        0x00CC => "MSI/Plessey",
+        0x00CD => "Standard 2 of 5 IATA",
+        0x00CE => "Standard 2 of 5",
+        0x00D3 => "Transmit Start/Stop",
+        0x00D4 => "Tri-Optic",
+        0x00D5 => "UCC/EAN-128",
+        0x00D6 => "Check Digit",
+        0x00D7 => "Check Digit Disable",
+        0x00D8 => "Check Digit Enable Interleaved 2 of 5 OPCC",
+        0x00D9 => "Check Digit Enable Interleaved 2 of 5 USS",
+        0x00DA => "Check Digit Enable Standard 2 of 5 OPCC",
+        0x00DB => "Check Digit Enable Standard 2 of 5 USS",
+        0x00DC => "Check Digit Enable One MSI Plessey",
+        0x00DD => "Check Digit Enable Two MSI Plessey",
+        0x00DE => "Check Digit Codabar Enable",
+        0x00DF => "Check Digit Code 39 Enable",
+        0x00F0 => "Transmit Check Digit",
+        0x00F1 => "Disable Check Digit Transmit",
+        0x00F2 => "Enable Check Digit Transmit",
+        0x00FB => "Symbology Identifier 1",
+        0x00FC => "Symbology Identifier 2",
+        0x00FD => "Symbology Identifier 3",
+        0x00FE => "Decoded Data",
+        0x00FF => "Decode Data Continued",
+        0x0100 => "Bar Space Data",
+        0x0101 => "Scanner Data Accuracy",
+        0x0102 => "Raw Data Polarity",
+        0x0103 => "Polarity Inverted Bar Code",
+        0x0104 => "Polarity Normal Bar Code",
+        0x0106 => "Minimum Length to Decode",
+        0x0107 => "Maximum Length to Decode",
+        0x0108 => "Discrete Length to Decode 1",
+        0x0109 => "Discrete Length to Decode 2",
+        0x010A => "Data Length Method",
+        0x010B => "DL Method Read any",
+        0x010C => "DL Method Check in Range",
+        0x010D => "DL Method Check for Discrete",
+        0x0110 => "Aztec Code",
+        0x0111 => "BC412",
+        0x0112 => "Channel Code",
+        0x0113 => "Code 16",
+        0x0114 => "Code 32",
+        0x0115 => "Code 49",
+        0x0116 => "Code One",
+        0x0117 => "Colorcode",
+        0x0118 => "Data Matrix",
+        0x0119 => "MaxiCode",
+        0x011A => "MicroPDF",
+        0x011B => "PDF-417",
+        0x011C => "PosiCode",
+        0x011D => "QR Code",
+        0x011E => "SuperCode",
+        0x011F => "UltraCode",
+        0x0120 => "USD-5 (Slug Code)",
+        0x0121 => "VeriCode",
+    ];
+    0x008D, "Scales" => named[
+        0x0001 => "Scales",
+        0x0020 => "Scale Device",
+        0x0021 => "Scale Class",
+        0x0030 => "Scale Attribute Report",
+        0x0031 => "Scale Control Report",
+        0x0032 => "Scale Data Report",
+        0x0033 => "Scale Status Report",
+        0x0034 => "Scale Weight Limit Report",
+        0x0035 => "Scale Statistics Report",
+        0x0040 => "Data Weight",
+        0x0041 => "Data Scaling",
+        0x0050 => "Weight Unit",
+        0x0051 => "Weight Unit Milligram",
+        0x0052 => "Weight Unit Gram",
+        0x0053 => "Weight Unit Kilogram",
+        0x0054 => "Weight Unit Carats",
+        0x0055 => "Weight Unit Taels",
+        0x0056 => "Weight Unit Grains",
+        0x0057 => "Weight Unit Pennyweights",
+        0x0058 => "Weight Unit Metric Ton",
+        0x0059 => "Weight Unit Avoir Ton",
+        0x005A => "Weight Unit Troy Ounce",
+        0x005B => "Weight Unit Ounce",
+        0x005C => "Weight Unit Pound",
+        0x0060 => "Calibration Count",
+        0x0061 => "Re-Zero Count",
+        0x0070 => "Scale Status",
+        0x0071 => "Scale Status Fault",
+        0x0072 => "Scale Status Stable at Center of Zero",
+        0x0073 => "Scale Status In Motion",
+        0x0074 => "Scale Status Weight Stable",
+        0x0075 => "Scale Status Under Zero",
+        0x0076 => "Scale Status Over Weight Limit",
+        0x0077 => "Scale Status Requires Calibration",
+        0x0078 => "Scale Status Requires Rezeroing",
+        0x0080 => "Zero Scale",
+        0x0081 => "Enforced Zero Return",
+    ];
+    0x008E, "Magnetic Stripe Reader" => named[
+        0x0001 => "MSR Device Read-Only",
+        0x0011 => "Track 1 Length",
+        0x0012 => "Track 2 Length",
+        0x0013 => "Track 3 Length",
+        0x0014 => "Track JIS Length",
+        0x0020 => "Track Data",
+        0x0021 => "Track 1 Data",
+        0x0022 => "Track 2 Data",
+        0x0023 => "Track 3 Data",
+        0x0024 => "Track JIS Data",
+    ];
+    0x0090, "Camera Control" => named[
+        0x0020 => "Camera Auto-focus",
+        0x0021 => "Camera Shutter",
+    ];
+    0x0091, "Arcade" => named[
+        0x0001 => "General Purpose IO Card",
+        0x0002 => "Coin Door",
+        0x0003 => "Watchdog Timer",
+        0x0030 => "General Purpose Analog Input State",
+        0x0031 => "General Purpose Digital Input State",
+        0x0032 => "General Purpose Optical Input State",
+        0x0033 => "General Purpose Digital Output State",
+        0x0034 => "Number of Coin Doors",
+        0x0035 => "Coin Drawer Drop Count",
+        0x0036 => "Coin Drawer Start",
+        0x0037 => "Coin Drawer Service",
+        0x0038 => "Coin Drawer Tilt",
+        0x0039 => "Coin Door Test",
+        0x0040 => "Coin Door Lockout",
+        0x0041 => "Watchdog Timeout",
+        0x0042 => "Watchdog Action",
+        0x0043 => "Watchdog Reboot",
+        0x0044 => "Watchdog Restart",
+        0x0045 => "Alarm Input",
+        0x0046 => "Coin Door Counter",
+        0x0047 => "I/O Direction Mapping",
+        0x0048 => "Set I/O Direction Mapping",
+        0x0049 => "Extended Optical Input State",
+        0x004A => "Pin Pad Input State",
+        0x004B => "Pin Pad Status",
+        0x004C => "Pin Pad Output",
+        0x004D => "Pin Pad Command",
+    ];
+    0xF1D0, "FIDO Alliance" => named[
+        0x0001 => "U2F Authenticator Device",
+        0x0020 => "Input Report Data",
+        0x0021 => "Output Report Data",
+    ];
+}